@@ -1,8 +1,9 @@
 #![cfg_attr(not(test), no_std)]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, String,
-    Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env,
+    String, Symbol, Vec,
 };
 
 // Storage key constants
@@ -11,9 +12,36 @@ const APPLICATION_COUNT_PREFIX: &str = "a_count_";
 const APPLICATION_PREFIX: &str = "a_";
 const APPLICANT_PREFIX: &str = "ap_";
 const MILESTONES_PREFIX: &str = "milestones";
+// Milestone plans proposed by a student and awaiting the linked school
+const PROPOSED_MILESTONES_PREFIX: &str = "ms_prop";
+// Evidence a student submitted for a milestone
+const MILESTONE_EVIDENCE_PREFIX: &str = "ms_evid";
+// Vesting schedule attached to a student's application
+const VESTING_PREFIX: &str = "vesting";
+// Milestone plan versioning and amendments
+const PLAN_VERSION_PREFIX: &str = "ms_ver";
+const PLAN_HISTORY_PREFIX: &str = "ms_hist";
+const AMENDMENT_PREFIX: &str = "ms_amend";
+const AMENDMENT_SPONSOR_PREFIX: &str = "amd_spon";
+// Payout address a school registers for direct tuition payments
+const SCHOOL_PAYOUT_PREFIX: &str = "sch_pay";
+// Who receives a student's claims
+const PAYEE_PREFIX: &str = "payee";
 const ADMIN_KEY: &str = "admin";
 const SCHOOL_REG_PREFIX: &str = "school_reg";
 const POOL_SCHOOL_PREFIX: &str = "pool_school";
+// Actual token balance held on behalf of each pool: ("pool_bal", pool_id, token)
+const POOL_BALANCE_PREFIX: &str = "pool_bal";
+
+// Donor ledger: append-only donation entries per pool, plus a per-donor index
+// of (pool_id, entry_index) references into those lists
+const DONATION_COUNT_PREFIX: &str = "don_count";
+const DONATION_PREFIX: &str = "donation";
+const DONOR_HISTORY_COUNT_PREFIX: &str = "dh_count";
+const DONOR_HISTORY_PREFIX: &str = "dh";
+
+// Upper bound on entries returned by a single paginated view call
+const MAX_PAGE_SIZE: u32 = 50;
 
 // TODO: Replace with real implementation from issue #XYZ
 // Emergency withdrawal storage keys
@@ -23,10 +51,9 @@ const GRACE_PERIOD_SECS: u64 = 86400; // 24 hours
 // Application and claim tracking constants
 const APPLICATION_STATUS_PREFIX: &str = "app_status";
 const CLAIMED_AMOUNT_PREFIX: &str = "claimed_amount";
-const APPLICATION_STATUS_APPROVED: &str = "Approved";
-const APPLICATION_STATUS_REJECTED: &str = "Rejected";
 
-// Protocol fees accumulator - tracks unclaimed fees collected from operations
+// Protocol fees accumulator - tracks unclaimed fees collected from operations,
+// keyed per token as ("unclaimed_fees", token)
 const UNCLAIMED_FEES: &str = "unclaimed_fees";
 
 // Creation fee key - stores the fee charged when creating a new pool
@@ -42,6 +69,32 @@ const REFUND_GRACE_PERIOD_LEDGERS: u32 = 17_280; // ~24 hours at 5s/ledger
 const MAX_DESCRIPTION_LENGTH: usize = 500;
 const MAX_URL_LENGTH: usize = 256;
 const MAX_IMAGE_HASH_LENGTH: usize = 64;
+const MAX_SOCIAL_URLS: u32 = 5;
+const MAX_TAGS: u32 = 10;
+const MAX_TAG_LENGTH: usize = 32;
+const POOL_METADATA_PREFIX: &str = "metadata";
+
+// Application payload validation constraints
+const MAX_SUMMARY_LENGTH: usize = 280;
+
+// Appeals against rejected applications: one per student, filed within
+// APPEAL_WINDOW_SECS of the rejection and ruled on by the admin or arbitrator
+const ARBITRATOR_KEY: &str = "arbitrator";
+const APPEAL_PREFIX: &str = "appeal";
+const REJECTED_AT_PREFIX: &str = "rej_at";
+const APPEAL_WINDOW_SECS: u64 = 604_800; // 7 days
+
+// Eligibility attestations issued by registered schools, and the attestation
+// kinds a pool requires from its linked school before students may apply
+const ATTESTATION_PREFIX: &str = "attest";
+const REQUIRED_ATTESTATIONS_PREFIX: &str = "req_att";
+
+// Students approved past a pool's max_recipients, ordered by application index
+const WAITLIST_PREFIX: &str = "waitlist";
+
+// Audit trail of sponsor edits to pool parameters
+const PARAM_HISTORY_COUNT_PREFIX: &str = "ph_count";
+const PARAM_HISTORY_PREFIX: &str = "ph";
 
 // ─── Event Topics ────────────────────────────────────────────────────────
 
@@ -54,16 +107,41 @@ const SCHOOL_REGISTERED: Symbol = symbol_short!("schl_reg");
 
 // Issue #954: named constants for previously-uneventful state-changing functions
 const APP_APPROVED: Symbol = symbol_short!("app_aprvd");
+const APP_STATUS_CHANGED: Symbol = symbol_short!("app_stat");
+const APPROVED_AMOUNT_ADJUSTED: Symbol = symbol_short!("amt_adj");
+const APP_WITHDRAWN: Symbol = symbol_short!("app_wdrn");
 const MILESTONES_SET: Symbol = symbol_short!("mile_set");
+const MILESTONES_PROPOSED: Symbol = symbol_short!("mile_prop");
+const MILESTONE_SUBMITTED: Symbol = symbol_short!("mile_sub");
+const MILESTONE_VERIFIED: Symbol = symbol_short!("mile_vrfy");
+const MILESTONE_PAID: Symbol = symbol_short!("mile_paid");
+const VESTING_SET: Symbol = symbol_short!("vest_set");
+const AMENDMENT_PROPOSED: Symbol = symbol_short!("amd_prop");
+const AMENDMENT_CONSENTED: Symbol = symbol_short!("amd_cnst");
+const AMENDMENT_APPLIED: Symbol = symbol_short!("amd_apld");
+const AMENDMENT_SPONSOR_SET: Symbol = symbol_short!("amd_spon");
+const SCHOOL_PAYOUT_SET: Symbol = symbol_short!("sch_pay");
+const PAYEE_SET: Symbol = symbol_short!("payee_set");
 const FUNDS_CLAIMED: Symbol = symbol_short!("fund_clmd");
 const FEES_CLAIMED: Symbol = symbol_short!("fees_clmd");
 const DONATION_REFUND: Symbol = symbol_short!("don_refnd");
 const DEADLINE_SET: Symbol = symbol_short!("ddln_set");
 const POOL_STATE_SET: Symbol = symbol_short!("pool_stat");
-const SCHOOL_REG: Symbol = symbol_short!("schl_reg");
 const ADMIN_SET: Symbol = symbol_short!("admin_set");
 // Issue #954: shared constant replacing inline Symbol::new(&env, "creation_fee_updated")
 const FEE_UPDATED: Symbol = symbol_short!("fee_upd");
+const GOAL_REACHED: Symbol = symbol_short!("goal_rchd");
+const METADATA_UPDATED: Symbol = symbol_short!("meta_upd");
+const GOAL_UPDATED: Symbol = symbol_short!("goal_upd");
+const APP_DEADLINE_UPDATED: Symbol = symbol_short!("appdl_upd");
+const REVIEW_DEADLINE_SET: Symbol = symbol_short!("rvw_set");
+const ARBITRATOR_SET: Symbol = symbol_short!("arb_set");
+const APPEAL_FILED: Symbol = symbol_short!("apl_file");
+const APPEAL_RESOLVED: Symbol = symbol_short!("apl_rslv");
+const WAITLIST_PROMOTED: Symbol = symbol_short!("wl_promo");
+const ATTESTATION_ISSUED: Symbol = symbol_short!("att_iss");
+const ATTESTATION_REVOKED: Symbol = symbol_short!("att_rvk");
+const REQUIRED_ATTESTATIONS_SET: Symbol = symbol_short!("att_req");
 
 // ─── Typed Error Enum (Issue #955) ───────────────────────────────────────
 
@@ -103,6 +181,111 @@ pub enum ContractError {
     NoContributionToRefund = 13,
     /// School address has not been registered by an admin.
     SchoolNotRegistered = 14,
+    /// Requested pool state change is not an edge of the pool state machine.
+    InvalidTransition = 15,
+    /// Caller is neither the sponsor nor the admin permitted for this transition.
+    UnauthorizedTransition = 16,
+    /// Donation would push `collected` past `goal` on a `RejectExcess` pool.
+    GoalExceeded = 17,
+    /// Token address does not match the funding token the pool was created with.
+    TokenMismatch = 18,
+    /// Outbound transfer would take a pool's token balance below zero.
+    InsufficientPoolBalance = 19,
+    /// A metadata URL is longer than `MAX_URL_LENGTH`.
+    UrlTooLong = 20,
+    /// The cover image hash is longer than `MAX_IMAGE_HASH_LENGTH`.
+    ImageHashTooLong = 21,
+    /// More than `MAX_SOCIAL_URLS` social links were supplied.
+    TooManySocialUrls = 22,
+    /// More than `MAX_TAGS` tags were supplied.
+    TooManyTags = 23,
+    /// A tag is empty or longer than `MAX_TAG_LENGTH`.
+    InvalidTag = 24,
+    /// New goal is lower than the amount the pool has already collected.
+    GoalBelowCollected = 25,
+    /// Application deadline cannot move earlier once applications exist.
+    DeadlineMovedEarlier = 26,
+    /// Application deadline is not after the current ledger timestamp.
+    InvalidDeadline = 27,
+    /// Application submitted after the pool's application deadline.
+    ApplicationDeadlinePassed = 28,
+    /// Approval attempted after the pool's review window has ended.
+    ReviewWindowClosed = 29,
+    /// Requested application status change is not allowed from the current status.
+    InvalidStatusTransition = 30,
+    /// Approved amount must be positive (and, when adjusting, lower than before).
+    InvalidApprovedAmount = 31,
+    /// Claim would take the student's total past their approved amount.
+    ExceedsApprovedAmount = 32,
+    /// The student has already claimed funds against this approval.
+    ClaimAlreadyMade = 33,
+    /// Approval, refund or withdrawal needs more than the pool's unreserved balance.
+    InsufficientUnreservedFunds = 34,
+    /// An application document URI is longer than `MAX_URL_LENGTH`.
+    DocumentUriTooLong = 35,
+    /// An application summary is longer than `MAX_SUMMARY_LENGTH`.
+    SummaryTooLong = 36,
+    /// Only rejected applications can be appealed.
+    ApplicationNotRejected = 37,
+    /// The appeal window after the rejection has ended.
+    AppealWindowClosed = 38,
+    /// The student has already appealed this rejection.
+    AppealAlreadyFiled = 39,
+    /// No appeal has been filed for this student.
+    AppealNotFound = 40,
+    /// The appeal has already been ruled on.
+    AppealAlreadyResolved = 41,
+    /// Caller is neither the admin nor the designated arbitrator.
+    UnauthorizedArbitrator = 42,
+    /// A pool's recipient cap must be at least one when set.
+    InvalidMaxRecipients = 43,
+    /// Student lacks a valid, unexpired attestation the pool requires.
+    AttestationMissing = 44,
+    /// No attestation of this kind exists for the student.
+    AttestationNotFound = 45,
+    /// Attestation expiry is not after the current ledger timestamp.
+    InvalidAttestationExpiry = 46,
+    /// Operation needs a pool linked to a school.
+    PoolSchoolNotSet = 47,
+    /// A milestone in a new plan is not `Pending`, has no amount, or is
+    /// already past due.
+    InvalidMilestonePlan = 48,
+    /// Claim is larger than the student's verified but unpaid milestones.
+    ExceedsVerifiedMilestones = 49,
+    /// Claim does not cover a whole number of verified milestones.
+    PartialMilestoneClaim = 50,
+}
+
+/// Disbursement error conditions, continuing the codes of `ContractError`.
+///
+/// A contract spec allows at most 50 cases per error enum, so codes from 51
+/// onwards live here. Raise them with `env.panic_with_error` exactly like
+/// `ContractError`.
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisbursementError {
+    /// Operation needs the student's application to be `Approved`.
+    ApplicationNotApproved = 51,
+    /// Milestone amounts do not add up to the student's approved amount.
+    MilestoneTotalMismatch = 52,
+    /// A milestone has been paid, so the plan can no longer be replaced.
+    MilestonePlanFrozen = 53,
+    /// The student has no proposed milestone plan awaiting approval.
+    MilestonePlanNotFound = 54,
+    /// Milestone index is outside the student's active plan.
+    MilestoneNotFound = 55,
+    /// Milestone is not in the status this operation needs.
+    InvalidMilestoneStatus = 56,
+    /// Vesting schedule does not satisfy `start <= cliff <= end` with `start < end`.
+    InvalidVestingSchedule = 57,
+    /// Claim is larger than the amount vested and not yet claimed.
+    ExceedsVestedAmount = 58,
+    /// The student has no milestone amendment awaiting consent.
+    AmendmentNotFound = 59,
+    /// Caller is not the student, linked school or sponsor of the plan.
+    NotAmendmentParty = 60,
+    /// Claims are routed to a school that has no registered payout address.
+    PayoutAddressNotSet = 61,
 }
 
 // Helper functions for timestamp/deadline edge-case tests
@@ -148,6 +331,48 @@ pub fn set_deadline(deadline: u64) -> Result<(), &'static str> {
     Ok(())
 }
 
+/// Where a student's application stands in a pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ApplicationStatus {
+    /// Submitted and awaiting a decision from the school.
+    Pending,
+    /// Approved by the school; the student may claim funds.
+    Approved,
+    /// Rejected by the school.
+    Rejected,
+    /// Withdrawn by the student.
+    Withdrawn,
+    /// Approved but queued until the pool has room for another recipient.
+    Waitlisted,
+    /// Approval taken back after the fact.
+    Revoked,
+}
+
+/// Application status transition table.
+///
+/// | From         | To                                              |
+/// |--------------|-------------------------------------------------|
+/// | `Pending`    | `Approved`, `Waitlisted`, `Rejected`, `Withdrawn` |
+/// | `Approved`   | `Withdrawn`, `Revoked`                          |
+/// | `Waitlisted` | `Approved`, `Withdrawn`                         |
+///
+/// `Rejected` moves to `Approved` only when an appeal is granted, which
+/// `resolve_appeal` applies directly rather than through this table.
+fn application_transition_allowed(from: &ApplicationStatus, to: &ApplicationStatus) -> bool {
+    matches!(
+        (from, to),
+        (ApplicationStatus::Pending, ApplicationStatus::Approved)
+            | (ApplicationStatus::Pending, ApplicationStatus::Waitlisted)
+            | (ApplicationStatus::Pending, ApplicationStatus::Rejected)
+            | (ApplicationStatus::Pending, ApplicationStatus::Withdrawn)
+            | (ApplicationStatus::Approved, ApplicationStatus::Withdrawn)
+            | (ApplicationStatus::Approved, ApplicationStatus::Revoked)
+            | (ApplicationStatus::Waitlisted, ApplicationStatus::Approved)
+            | (ApplicationStatus::Waitlisted, ApplicationStatus::Withdrawn)
+    )
+}
+
 /// Tracks a student's approved funding and how much has been streamed so far.
 ///
 /// `amount_claimed` starts at zero and increments with each partial withdrawal,
@@ -163,6 +388,107 @@ pub struct Application {
     pub amount_claimed: i128,
 }
 
+/// Who receives the funds a student claims.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Payee {
+    /// Claims are paid to the student's own address.
+    Student,
+    /// Claims are paid to the linked school's registered payout address,
+    /// e.g. for tuition.
+    School,
+}
+
+/// Linear release schedule for a student's approved amount.
+///
+/// Nothing vests before `cliff`; from then on the vested amount is
+/// `approved_amount * (now - start) / (end - start)`, reaching the full
+/// approved amount at `end`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+}
+
+/// What a student submits with an application.
+///
+/// The full application document lives off-chain; only its hash, location
+/// and a short summary are stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationPayload {
+    /// Hash of the off-chain application document.
+    pub content_hash: BytesN<32>,
+    /// Where the document can be fetched; at most `MAX_URL_LENGTH` bytes.
+    pub document_uri: String,
+    /// Short summary for reviewers; at most `MAX_SUMMARY_LENGTH` bytes.
+    pub summary: String,
+}
+
+/// Where an appeal against a rejection stands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealStatus {
+    /// Filed and awaiting a ruling.
+    Open,
+    /// Rejection overturned; the application is now `Approved`.
+    Granted,
+    /// Rejection upheld; the application stays `Rejected`.
+    Denied,
+}
+
+/// A student's appeal against the rejection of their application.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    /// Hash of the off-chain statement of reasons.
+    pub reason_hash: BytesN<32>,
+    /// Ledger timestamp at which the appeal was filed.
+    pub filed_at: u64,
+    pub status: AppealStatus,
+}
+
+/// A school's statement about a student, such as current enrolment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// Ledger timestamp at which the attestation was issued.
+    pub issued_at: u64,
+    /// The attestation is valid while the ledger timestamp is before this.
+    pub expires_at: u64,
+}
+
+/// One submitted application as returned by `list_applications`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationEntry {
+    /// Submission index within the pool, starting at 1.
+    pub index: u32,
+    pub student: Address,
+    /// Applications stored as text by earlier contract versions are returned
+    /// with that text as the summary and an all-zero content hash.
+    pub payload: ApplicationPayload,
+    /// The student's current status in the pool.
+    pub status: ApplicationStatus,
+}
+
+/// A single donation recorded in a pool's donor ledger.
+///
+/// Entries are append-only: refunds and withdrawals never modify them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationEntry {
+    pub pool_id: u32,
+    pub donor: Address,
+    pub token: Address,
+    /// Amount actually received by the contract for this donation.
+    pub amount: u128,
+    /// Ledger timestamp at which the donation was recorded.
+    pub timestamp: u64,
+}
+
 /// Pool state machine enum representing the lifecycle of a donation pool.
 ///
 /// # State Machine
@@ -177,16 +503,32 @@ pub struct Application {
 ///
 /// # State Transitions
 ///
-/// Current state transition functions:
-/// - `create_pool()` / `create_pool_for_school()`: Initializes pool to `Active`
-/// - `donate()`: Validates pool is `Active` (rejects if `Closed`)
-/// - `close_pool()`: Requires pool to be `Disbursed` or `Cancelled` before closing
+/// Every state change goes through [`transition_authority`], which lists the
+/// permitted edges and who may authorize each one:
+///
+/// | From                          | To          | Entrypoint         | Authorized by    |
+/// |-------------------------------|-------------|--------------------|------------------|
+/// | `Active`                      | `Paused`    | `pause_pool()`     | sponsor or admin |
+/// | `Paused`                      | `Active`    | `resume_pool()`    | sponsor or admin |
+/// | `Active`                      | `Completed` | `mark_completed()` | sponsor or admin |
+/// | `Active` / `Paused`           | `Cancelled` | `cancel_pool()`    | sponsor or admin |
+/// | `Completed`                   | `Cancelled` | `cancel_pool()`    | admin            |
+/// | `Completed`                   | `Disbursed` | `mark_disbursed()` | admin            |
+/// | `Disbursed` / `Cancelled`     | `Closed`    | `close_pool()`     | sponsor          |
+///
+/// Any other edge is rejected with `ContractError::InvalidTransition`.
 ///
 /// # Validation Rules
 ///
-/// - `donate()` only accepts donations if state is `Active`
+/// - `donate()` only accepts donations if state is `Active` (or `Completed`
+///   for pools created with `OverfundingPolicy::AcceptAll`)
+/// - Donations that bring `collected` up to `goal` move an `Active` pool to
+///   `Completed` automatically
+/// - `apply_to_pool()`, `approve_application()`, `claim_funds()` and
+///   `verify_milestone()` only run while the pool is `Active` or `Completed`
+/// - `withdraw_unallocated_funds()` is rejected while the pool is `Paused`
+///   or `Cancelled`
 /// - `close_pool()` only allows closing from `Disbursed` or `Cancelled` states
-/// - Other state transitions are not yet implemented (see TODO comments)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PoolState {
@@ -204,6 +546,68 @@ pub enum PoolState {
     Closed,
 }
 
+/// How a pool treats donations that would take `collected` past `goal`.
+///
+/// Chosen by the sponsor at `create_pool()` and fixed for the life of the pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OverfundingPolicy {
+    /// Reject any donation that would push `collected` above `goal`.
+    RejectExcess,
+    /// Accept only the part of a donation needed to reach `goal`; the
+    /// remainder is never taken from (i.e. is refunded to) the donor.
+    CapAndRefund,
+    /// Accept every donation, and keep accepting after the pool is `Completed`.
+    AcceptAll,
+}
+
+/// Stage of a pool's timeline, as reported by `get_pool_phase()`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolPhase {
+    /// Application deadline not reached and the pool is still taking
+    /// donations.
+    Fundraising,
+    /// Application deadline not reached and fundraising has ended
+    /// (`Completed`).
+    Applications,
+    /// Application deadline passed; the school may still approve until the
+    /// review window ends.
+    Review,
+    /// Review window over (or the pool is `Disbursed`); funds go to students.
+    Disbursement,
+    /// Pool was cancelled or closed; its timeline has ended.
+    Ended,
+}
+
+/// Party allowed to authorize a pool state transition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TransitionAuthority {
+    Sponsor,
+    Admin,
+    SponsorOrAdmin,
+}
+
+/// Pool state transition table.
+///
+/// Returns who may authorize the move from `from` to `to`, or `None` if the
+/// edge is not part of the state machine.
+fn transition_authority(from: &PoolState, to: &PoolState) -> Option<TransitionAuthority> {
+    match (from, to) {
+        (PoolState::Active, PoolState::Paused)
+        | (PoolState::Paused, PoolState::Active)
+        | (PoolState::Active, PoolState::Completed)
+        | (PoolState::Active, PoolState::Cancelled)
+        | (PoolState::Paused, PoolState::Cancelled) => Some(TransitionAuthority::SponsorOrAdmin),
+        (PoolState::Completed, PoolState::Cancelled)
+        | (PoolState::Completed, PoolState::Disbursed) => Some(TransitionAuthority::Admin),
+        (PoolState::Disbursed, PoolState::Closed) | (PoolState::Cancelled, PoolState::Closed) => {
+            Some(TransitionAuthority::Sponsor)
+        }
+        _ => None,
+    }
+}
+
 /// Pool information
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -211,9 +615,89 @@ pub struct Pool {
     pub sponsor: Address,
     pub goal: u128,
     pub collected: u128,
+    /// Approved but not yet claimed amounts; never more than the pool balance.
+    pub reserved: u128,
     pub is_closed: bool,
     pub state: PoolState,
     pub application_deadline: u64,
+    /// End of the school review window, if the sponsor has set one.
+    /// `approve_application()` is closed after this timestamp.
+    pub review_deadline: Option<u64>,
+    pub overfunding_policy: OverfundingPolicy,
+    /// The only token this pool accepts and pays out in, fixed at creation.
+    pub token: Address,
+    /// Most students that may be `Approved` at once; `None` for no limit.
+    pub max_recipients: Option<u32>,
+    /// Number of applications currently `Approved`.
+    pub recipient_count: u32,
+}
+
+/// Broad category a pool is listed under.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolCategory {
+    Scholarship,
+    Research,
+    Infrastructure,
+    Community,
+    Other,
+}
+
+/// Descriptive pool information shown to donors and applicants.
+///
+/// Written with empty links, no tags and `PoolCategory::Other` at creation;
+/// the sponsor fills it in with `update_pool_metadata()`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMetadata {
+    pub title: String,
+    pub description: String,
+    /// Project website; at most `MAX_URL_LENGTH` bytes, empty if unset.
+    pub website_url: String,
+    /// Up to `MAX_SOCIAL_URLS` links, each at most `MAX_URL_LENGTH` bytes.
+    pub social_urls: Vec<String>,
+    /// Content hash of the cover image; at most `MAX_IMAGE_HASH_LENGTH` bytes.
+    pub cover_image_hash: String,
+    pub category: PoolCategory,
+    /// Up to `MAX_TAGS` non-empty tags, each at most `MAX_TAG_LENGTH` bytes.
+    pub tags: Vec<String>,
+}
+
+/// Pool parameter the sponsor may change after creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolParameter {
+    Goal,
+    ApplicationDeadline,
+    ReviewDeadline,
+}
+
+/// One entry in a pool's parameter history.
+///
+/// Deadlines are stored widened to `u128` so both parameters share a type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParameterChange {
+    pub parameter: PoolParameter,
+    pub old_value: u128,
+    pub new_value: u128,
+    pub timestamp: u64,
+}
+
+/// Where a milestone stands in its lifecycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MilestoneStatus {
+    /// Not yet reached.
+    Pending,
+    /// The student has submitted evidence that it was reached.
+    Submitted,
+    /// The school has confirmed it; the amount can be claimed.
+    Verified,
+    /// The amount has been released to the student.
+    Paid,
+    /// The due timestamp passed before the milestone was verified.
+    Expired,
 }
 
 /// Milestone for streaming disbursements
@@ -221,6 +705,27 @@ pub struct Pool {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Milestone {
     pub amount: u128,
+    /// Hash of the off-chain description of what must be achieved.
+    pub description_hash: BytesN<32>,
+    /// Ledger timestamp by which the milestone must be verified.
+    pub due_at: u64,
+    pub status: MilestoneStatus,
+}
+
+/// A proposed replacement for the unpaid part of an active milestone plan.
+///
+/// `Paid` milestones carry over unchanged; `milestones` replaces everything
+/// else once every required party has consented.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneAmendment {
+    pub proposer: Address,
+    pub milestones: Vec<Milestone>,
+    pub student_consent: bool,
+    pub school_consent: bool,
+    /// Whether the pool sponsor must also consent, fixed when proposed.
+    pub sponsor_required: bool,
+    pub sponsor_consent: bool,
 }
 
 // TODO: Replace with real implementation from issue #XYZ
@@ -290,9 +795,143 @@ impl Contract {
             .expect("School not registered")
     }
 
+    /// Registered school sets the address that receives claims routed to it
+    /// with `Payee::School`. Emits `SCHOOL_PAYOUT_SET` with the new address.
+    ///
+    /// # Panics
+    /// - `ContractError::SchoolNotRegistered` if the school is not registered
+    pub fn set_school_payout_address(env: Env, school: Address, payout: Address) {
+        school.require_auth();
+
+        if !Self::is_school_registered(env.clone(), school.clone()) {
+            env.panic_with_error(ContractError::SchoolNotRegistered);
+        }
+
+        let payout_key = (Symbol::new(&env, SCHOOL_PAYOUT_PREFIX), school.clone());
+        env.storage().persistent().set(&payout_key, &payout);
+
+        env.events().publish((SCHOOL_PAYOUT_SET, school), payout);
+    }
+
+    /// Get the payout address a school has registered, if any.
+    pub fn get_school_payout_address(env: Env, school: Address) -> Option<Address> {
+        let payout_key = (Symbol::new(&env, SCHOOL_PAYOUT_PREFIX), school);
+        env.storage().persistent().get::<_, Address>(&payout_key)
+    }
+
+    /// Designate the address that may rule on appeals alongside the admin.
+    ///
+    /// Only the platform admin may call this; setting a new arbitrator
+    /// replaces the previous one.
+    ///
+    /// # Panics
+    /// - `ContractError::AdminNotSet` if no admin is configured
+    pub fn set_arbitrator(env: Env, arbitrator: Address) {
+        let admin = Self::read_admin(&env)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
+        admin.require_auth();
+
+        let arbitrator_key = Symbol::new(&env, ARBITRATOR_KEY);
+        env.storage().persistent().set(&arbitrator_key, &arbitrator);
+
+        env.events().publish((ARBITRATOR_SET,), arbitrator);
+    }
+
+    /// Get the designated appeal arbitrator, if any.
+    pub fn get_arbitrator(env: Env) -> Option<Address> {
+        let arbitrator_key = Symbol::new(&env, ARBITRATOR_KEY);
+        env.storage()
+            .persistent()
+            .get::<_, Address>(&arbitrator_key)
+    }
+
+    /// Registered school attests a fact about a student, identified by
+    /// `kind` (for example `enrolled`), until `expires_at`.
+    ///
+    /// Issuing the same kind again replaces the earlier attestation.
+    /// Emits `ATTESTATION_ISSUED` with `(student, kind, expires_at)`.
+    ///
+    /// # Panics
+    /// - `ContractError::SchoolNotRegistered` if `school` is not registered
+    /// - `ContractError::InvalidAttestationExpiry` if `expires_at` is not in the future
+    pub fn issue_attestation(
+        env: Env,
+        school: Address,
+        student: Address,
+        kind: Symbol,
+        expires_at: u64,
+    ) {
+        school.require_auth();
+
+        if !Self::is_school_registered(env.clone(), school.clone()) {
+            env.panic_with_error(ContractError::SchoolNotRegistered);
+        }
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            env.panic_with_error(ContractError::InvalidAttestationExpiry);
+        }
+
+        let attestation_key = (
+            Symbol::new(&env, ATTESTATION_PREFIX),
+            school.clone(),
+            student.clone(),
+            kind.clone(),
+        );
+        let attestation = Attestation {
+            issued_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&attestation_key, &attestation);
+
+        env.events()
+            .publish((ATTESTATION_ISSUED, school), (student, kind, expires_at));
+    }
+
+    /// School withdraws an attestation it issued.
+    ///
+    /// Emits `ATTESTATION_REVOKED` with `(student, kind)`.
+    ///
+    /// # Panics
+    /// - `ContractError::AttestationNotFound` if the school has not attested `kind`
+    pub fn revoke_attestation(env: Env, school: Address, student: Address, kind: Symbol) {
+        school.require_auth();
+
+        let attestation_key = (
+            Symbol::new(&env, ATTESTATION_PREFIX),
+            school.clone(),
+            student.clone(),
+            kind.clone(),
+        );
+        if !env.storage().persistent().has(&attestation_key) {
+            env.panic_with_error(ContractError::AttestationNotFound);
+        }
+        env.storage().persistent().remove(&attestation_key);
+
+        env.events()
+            .publish((ATTESTATION_REVOKED, school), (student, kind));
+    }
+
+    /// Get an attestation issued by `school`, whether or not it has expired.
+    pub fn get_attestation(
+        env: Env,
+        school: Address,
+        student: Address,
+        kind: Symbol,
+    ) -> Option<Attestation> {
+        let attestation_key = (Symbol::new(&env, ATTESTATION_PREFIX), school, student, kind);
+        env.storage()
+            .persistent()
+            .get::<_, Attestation>(&attestation_key)
+    }
+
     // ─── Pool Management ─────────────────────────────────────────────────────
 
     /// Create a new donation / sponsorship pool.
+    ///
+    /// `max_recipients` caps how many students can be `Approved` at once;
+    /// approvals past the cap are waitlisted. `None` means no cap.
     pub fn create_pool(
         env: Env,
         creator: Address,
@@ -300,10 +939,16 @@ impl Contract {
         description: String,
         goal: u128,
         application_deadline: u64,
+        overfunding_policy: OverfundingPolicy,
+        token: Address,
+        max_recipients: Option<u32>,
     ) -> u32 {
-        if description.len() as u32 > MAX_DESCRIPTION_LENGTH as u32 {
+        if description.len() > MAX_DESCRIPTION_LENGTH as u32 {
             panic!("Description exceeds maximum length");
         }
+        if max_recipients == Some(0) {
+            env.panic_with_error(ContractError::InvalidMaxRecipients);
+        }
 
         let pool_count_key = Symbol::new(&env, POOL_COUNT);
         let mut pool_count: u32 = env
@@ -315,27 +960,31 @@ impl Contract {
         let pool_id = pool_count + 1;
         pool_count = pool_id;
 
-        // Legacy compatibility: keep old symbolic key constants reachable.
-        let _ = (
-            POOL_PREFIX,
-            CREATOR_SUFFIX,
-            GOAL_SUFFIX,
-            COLLECTED_SUFFIX,
-            CLOSED_SUFFIX,
-        );
-
-        let metadata_key = (Symbol::new(&env, "metadata"), pool_id);
-        env.storage()
-            .persistent()
-            .set(&metadata_key, &(title.clone(), description.clone()));
+        let metadata = PoolMetadata {
+            title: title.clone(),
+            description: description.clone(),
+            website_url: String::from_str(&env, ""),
+            social_urls: Vec::new(&env),
+            cover_image_hash: String::from_str(&env, ""),
+            category: PoolCategory::Other,
+            tags: Vec::new(&env),
+        };
+        let metadata_key = (Symbol::new(&env, POOL_METADATA_PREFIX), pool_id);
+        env.storage().persistent().set(&metadata_key, &metadata);
 
         let pool = Pool {
             sponsor: creator.clone(),
             goal,
             collected: 0u128,
+            reserved: 0u128,
             is_closed: false,
             state: PoolState::Active,
             application_deadline,
+            review_deadline: None,
+            overfunding_policy,
+            token,
+            max_recipients,
+            recipient_count: 0,
         };
 
         env.storage().persistent().set(&pool_id, &pool);
@@ -365,6 +1014,9 @@ impl Contract {
         goal: u128,
         school: Address,
         application_deadline: u64,
+        overfunding_policy: OverfundingPolicy,
+        token: Address,
+        max_recipients: Option<u32>,
     ) -> u32 {
         creator.require_auth();
 
@@ -379,6 +1031,9 @@ impl Contract {
             description,
             goal,
             application_deadline,
+            overfunding_policy,
+            token,
+            max_recipients,
         );
         let pool_school_key = (Symbol::new(&env, POOL_SCHOOL_PREFIX), pool_id);
         env.storage().persistent().set(&pool_school_key, &school);
@@ -394,74 +1049,55 @@ impl Contract {
             .expect("Pool school not set")
     }
 
-    /// Donate to an existing pool.
+    /// Donate to an existing pool in the pool's funding token.
+    ///
+    /// The donor must authorize the call; the accepted amount is transferred
+    /// from the donor to the contract before it is counted in `collected`.
+    /// The pool's [`OverfundingPolicy`] decides how much of `amount` is
+    /// accepted; reaching the goal moves the pool to `Completed`.
     pub fn donate(env: Env, pool_id: u32, donor: Address, amount: u128) {
-        let pool: Pool = env
+        donor.require_auth();
+
+        let mut pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        if pool.is_closed {
-            env.panic_with_error(ContractError::PoolIsClosed);
-        }
+        Self::ensure_accepting_donations(&env, &pool);
 
-        // TODO: Replace with real implementation from issue #XYZ
-        // Pool state validation
-        if pool.state != PoolState::Active {
-            env.panic_with_error(ContractError::InvalidPoolState);
+        if amount == 0 || amount > i128::MAX as u128 {
+            panic!("InvalidAmount");
         }
 
-        let new_collected = pool.collected + amount;
-        let updated_pool = Pool {
-            sponsor: pool.sponsor,
-            goal: pool.goal,
-            collected: new_collected,
-            is_closed: pool.is_closed,
-            state: pool.state,
-            application_deadline: pool.application_deadline,
-        };
-        env.storage().persistent().set(&pool_id, &updated_pool);
+        let amount = Self::accepted_donation(&env, &pool, amount);
 
-        let donor_index: u32 = env
-            .storage()
-            .persistent()
-            .get::<_, u32>(&(pool_id, "d_count"))
-            .unwrap_or(0);
-        let _ = donor;
-        env.storage()
-            .persistent()
-            .set(&(pool_id, "d_count"), &(donor_index + 1));
+        let token_client = token::Client::new(&env, &pool.token);
+        token_client.transfer(&donor, &env.current_contract_address(), &(amount as i128));
+        Self::credit_pool_balance(&env, pool_id, &pool.token, amount as i128);
+
+        let new_collected = pool
+            .collected
+            .checked_add(amount)
+            .expect("Collected amount overflow");
+        pool.collected = new_collected;
+        Self::complete_if_goal_reached(&env, pool_id, &mut pool);
+        env.storage().persistent().set(&pool_id, &pool);
+
+        Self::record_donation(&env, pool_id, &donor, &pool.token, amount);
+        // New funds may let the head of the waitlist be approved
+        Self::promote_waitlisted(&env, pool_id);
 
         // Emit donation event
         env.events().publish(
             (DONATION_MADE, pool_id),
             (donor.clone(), amount, new_collected),
         );
-        // Track unique donors
-        let donor_key = (pool_id, "donor", &donor);
-        if !env.storage().persistent().has(&donor_key) {
-            env.storage().persistent().set(&donor_key, &true);
-            let donor_count: u32 = env
-                .storage()
-                .persistent()
-                .get::<_, u32>(&(pool_id, "d_count"))
-                .unwrap_or(0);
-            env.storage()
-                .persistent()
-                .set(&(pool_id, "d_count"), &(donor_count + 1));
-        }
-
-        // Track individual donor's total contribution
-        let contrib_key = (pool_id, "contribution", &donor);
-        let current_contrib: u128 = env.storage().persistent().get(&contrib_key).unwrap_or(0);
-        env.storage()
-            .persistent()
-            .set(&contrib_key, &(current_contrib + amount));
     }
 
-    /// Get pool information as a tuple (id, creator, goal, collected, is_closed).
-    pub fn get_pool(env: Env, pool_id: u32) -> (u32, Address, u128, u128, bool, u64) {
+    /// Get pool information as a tuple
+    /// (id, creator, goal, collected, is_closed, application_deadline, token).
+    pub fn get_pool(env: Env, pool_id: u32) -> (u32, Address, u128, u128, bool, u64, Address) {
         let pool: Pool = env
             .storage()
             .persistent()
@@ -475,867 +1111,3215 @@ impl Contract {
             pool.collected,
             pool.is_closed,
             pool.application_deadline,
+            pool.token,
         )
     }
 
-    /// Get pool metadata as a tuple (title, description).
-    /// Returns empty strings if the pool or metadata does not exist.
-    pub fn get_pool_metadata(env: Env, pool_id: u32) -> (String, String) {
-        let metadata_key = (Symbol::new(&env, "metadata"), pool_id);
+    /// Get the token balance the contract currently holds on behalf of a pool.
+    ///
+    /// Credited by every inbound donation and debited by every outbound
+    /// transfer (claims, refunds, surplus and emergency withdrawals).
+    pub fn get_pool_balance(env: Env, pool_id: u32, token_address: Address) -> i128 {
+        let balance_key = (
+            Symbol::new(&env, POOL_BALANCE_PREFIX),
+            pool_id,
+            token_address,
+        );
         env.storage()
             .persistent()
-            .get::<_, (String, String)>(&metadata_key)
-            .unwrap_or_else(|| (String::from_str(&env, ""), String::from_str(&env, "")))
+            .get::<_, i128>(&balance_key)
+            .unwrap_or(0)
     }
 
-    // Note: try_get_pool is auto-generated by Soroban SDK from get_pool
-    // Commenting out manual implementation to avoid duplicate definition
-    // /// Safely retrieve pool information.
-    // pub fn try_get_pool(env: Env, pool_id: u32) -> Option<(u32, Address, u128, u128, bool)> {
-    //     env.storage()
-    //         .persistent()
-    //         .get::<_, Pool>(&pool_id)
-    //         .map(|pool| {
-    //             (
-    //                 pool_id,
-    //                 pool.sponsor,
-    //                 pool.goal,
-    //                 pool.collected,
-    //                 pool.is_closed,
-    //             )
-    //         })
-    // }
-
-    /// Get the total amount raised for a pool.
-    pub fn get_total_raised(env: Env, pool_id: u32) -> u128 {
+    /// Get the amount a pool has reserved for approved students and not yet
+    /// paid out.
+    pub fn get_pool_reserved(env: Env, pool_id: u32) -> u128 {
         let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        pool.collected
+        pool.reserved
     }
 
-    /// Close a donation pool.
-    pub fn close_pool(env: Env, pool_id: u32) {
+    /// Get the funding token a pool was created with.
+    pub fn get_pool_token(env: Env, pool_id: u32) -> Address {
         let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        pool.sponsor.require_auth();
+        pool.token
+    }
 
-        if pool.state != PoolState::Disbursed && pool.state != PoolState::Cancelled {
-            env.panic_with_error(ContractError::PoolNotDisbursedOrRefunded);
-        }
+    /// Get the descriptive metadata of a pool.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if the pool has no metadata
+    pub fn get_pool_metadata(env: Env, pool_id: u32) -> PoolMetadata {
+        let metadata_key = (Symbol::new(&env, POOL_METADATA_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, PoolMetadata>(&metadata_key)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound))
+    }
 
-        let updated_pool = Pool {
-            sponsor: pool.sponsor,
-            goal: pool.goal,
-            collected: pool.collected,
-            is_closed: true,
-            state: pool.state,
-            application_deadline: pool.application_deadline,
-        };
+    /// Replace the descriptive metadata of a pool.
+    ///
+    /// Only the pool sponsor may call this, and not once the pool is closed.
+    /// Emits `METADATA_UPDATED` with the new metadata.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `"Description exceeds maximum length"` if the description is too long
+    /// - `ContractError::UrlTooLong`, `ImageHashTooLong`, `TooManySocialUrls`,
+    ///   `TooManyTags` or `InvalidTag` if a field breaks its limit
+    pub fn update_pool_metadata(env: Env, pool_id: u32, metadata: PoolMetadata) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        env.storage().persistent().set(&pool_id, &updated_pool);
+        pool.sponsor.require_auth();
 
-        // Emit pool closed event
-        env.events().publish(
-            (POOL_CLOSED, pool_id),
-            (updated_pool.sponsor.clone(), updated_pool.collected),
-        );
-    }
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
 
-    /// Get the total number of pools.
-    pub fn get_pool_count(env: Env) -> u32 {
-        let pool_count_key = Symbol::new(&env, POOL_COUNT);
-        env.storage()
-            .persistent()
-            .get::<_, u32>(&pool_count_key)
-            .unwrap_or(0)
+        Self::validate_metadata(&env, &metadata);
+
+        let metadata_key = (Symbol::new(&env, POOL_METADATA_PREFIX), pool_id);
+        env.storage().persistent().set(&metadata_key, &metadata);
+
+        env.events()
+            .publish((METADATA_UPDATED, pool_id), (pool.sponsor, metadata));
     }
 
-    /// Get the number of unique donors for a pool.
-    pub fn get_donor_count(env: Env, pool_id: u32) -> u32 {
-        // Verify the pool exists first
-        let _pool: Pool = env
+    /// Set the attestation kinds students need from the pool's linked school
+    /// before they may apply. An empty list removes the requirement.
+    ///
+    /// Only the pool sponsor may call this; applications already submitted
+    /// are not re-checked.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if the pool does not exist
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::PoolSchoolNotSet` if the pool has no linked school
+    pub fn set_required_attestations(env: Env, pool_id: u32, kinds: Vec<Symbol>) {
+        let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
+        pool.sponsor.require_auth();
+
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+        let pool_school_key = (Symbol::new(&env, POOL_SCHOOL_PREFIX), pool_id);
+        if !env.storage().persistent().has(&pool_school_key) {
+            env.panic_with_error(ContractError::PoolSchoolNotSet);
+        }
+
+        let required_key = (Symbol::new(&env, REQUIRED_ATTESTATIONS_PREFIX), pool_id);
+        env.storage().persistent().set(&required_key, &kinds);
+
+        env.events()
+            .publish((REQUIRED_ATTESTATIONS_SET, pool_id), kinds);
+    }
+
+    /// Get the attestation kinds a pool requires from applicants.
+    pub fn get_required_attestations(env: Env, pool_id: u32) -> Vec<Symbol> {
+        let required_key = (Symbol::new(&env, REQUIRED_ATTESTATIONS_PREFIX), pool_id);
         env.storage()
             .persistent()
-            .get::<_, u32>(&(pool_id, "d_count"))
-            .unwrap_or(0)
+            .get::<_, Vec<Symbol>>(&required_key)
+            .unwrap_or(Vec::new(&env))
     }
 
-    /// Get the total contribution of a specific donor to a specific pool.
-    pub fn get_contribution(env: Env, pool_id: u32, donor: Address) -> u128 {
-        // Verify the pool exists first
-        let _pool: Pool = env
+    /// Change the funding goal of a pool.
+    ///
+    /// Only the pool sponsor may call this, while the pool is `Active` or
+    /// `Paused`. Lowering the goal to exactly `collected` completes an
+    /// `Active` pool. The previous value is appended to the pool's parameter
+    /// history and `GOAL_UPDATED` is emitted with `(old_goal, new_goal)`.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` if the pool is not Active or Paused
+    /// - `ContractError::GoalBelowCollected` if `new_goal < collected`
+    pub fn update_pool_goal(env: Env, pool_id: u32, new_goal: u128) {
+        let mut pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        env.storage()
-            .persistent()
-            .get::<_, u128>(&(pool_id, "contribution", &donor))
-            .unwrap_or(0)
-    }
+        pool.sponsor.require_auth();
 
-    /// Student applies to a school-linked pool.
-    pub fn apply_to_pool(env: Env, pool_id: u32, student: Address, application_data: String) {
-        student.require_auth();
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+        if pool.state != PoolState::Active && pool.state != PoolState::Paused {
+            env.panic_with_error(ContractError::InvalidPoolState);
+        }
+        if new_goal < pool.collected {
+            env.panic_with_error(ContractError::GoalBelowCollected);
+        }
+
+        let old_goal = pool.goal;
+        pool.goal = new_goal;
+        Self::record_parameter_change(&env, pool_id, PoolParameter::Goal, old_goal, new_goal);
+        env.events()
+            .publish((GOAL_UPDATED, pool_id), (old_goal, new_goal));
+
+        Self::complete_if_goal_reached(&env, pool_id, &mut pool);
+        env.storage().persistent().set(&pool_id, &pool);
+    }
 
-        let _: Pool = env
+    /// Change the application deadline of a pool.
+    ///
+    /// Only the pool sponsor may call this. The new deadline must be in the
+    /// future and before any review deadline and, once any student has
+    /// applied, may not be earlier than the current one. The previous value
+    /// is appended to the pool's parameter history and `APP_DEADLINE_UPDATED`
+    /// is emitted with `(old_deadline, new_deadline)`.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidDeadline` if `new_deadline` is not in the future
+    ///   or not before the review deadline
+    /// - `ContractError::DeadlineMovedEarlier` if applications exist and the
+    ///   deadline would move earlier
+    pub fn update_application_deadline(env: Env, pool_id: u32, new_deadline: u64) {
+        let mut pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        let applicant_key = (
-            Symbol::new(&env, APPLICANT_PREFIX),
-            pool_id,
-            student.clone(),
-        );
-        if env.storage().persistent().has(&applicant_key) {
-            env.panic_with_error(ContractError::DuplicateApplication);
+        pool.sponsor.require_auth();
+
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+        if new_deadline <= env.ledger().timestamp()
+            || pool
+                .review_deadline
+                .is_some_and(|review| new_deadline >= review)
+        {
+            env.panic_with_error(ContractError::InvalidDeadline);
         }
 
         let count_key = (Symbol::new(&env, APPLICATION_COUNT_PREFIX), pool_id);
-        let mut app_count: u32 = env
+        let app_count: u32 = env
             .storage()
             .persistent()
             .get::<_, u32>(&count_key)
             .unwrap_or(0);
-        app_count += 1;
-
-        let app_key = (Symbol::new(&env, APPLICATION_PREFIX), pool_id, app_count);
-        env.storage()
-            .persistent()
-            .set(&app_key, &(app_count, student.clone(), application_data));
-
-        env.storage().persistent().set(&applicant_key, &true);
-        env.storage().persistent().set(&count_key, &app_count);
-
-        let pending = String::from_str(&env, "Pending");
-        Self::set_application_status(env.clone(), pool_id, student.clone(), pending);
-
-        // Emit application/contribution event with privacy flag (default: false for public)
-        env.events().publish(
-            (APPLICATION_SUBMITTED, pool_id),
-            (student.clone(), app_count, false), // false = public application
-        );
-    }
-
-    /// School approves or rejects a student's application.
-    pub fn approve_application(
-        env: Env,
-        pool_id: u32,
-        school: Address,
-        student: Address,
-        approved: bool,
-    ) {
-        school.require_auth();
-
-        let linked_school = Self::get_pool_school(env.clone(), pool_id);
-        if linked_school != school {
-            env.panic_with_error(ContractError::OnlyLinkedSchoolCanApprove);
+        if app_count > 0 && new_deadline < pool.application_deadline {
+            env.panic_with_error(ContractError::DeadlineMovedEarlier);
         }
 
-        let applicant_key = (
-            Symbol::new(&env, APPLICANT_PREFIX),
+        let old_deadline = pool.application_deadline;
+        pool.application_deadline = new_deadline;
+        env.storage().persistent().set(&pool_id, &pool);
+
+        Self::record_parameter_change(
+            &env,
             pool_id,
-            student.clone(),
+            PoolParameter::ApplicationDeadline,
+            old_deadline as u128,
+            new_deadline as u128,
+        );
+        env.events().publish(
+            (APP_DEADLINE_UPDATED, pool_id),
+            (old_deadline, new_deadline),
         );
-        if !env.storage().persistent().has(&applicant_key) {
-            env.panic_with_error(ContractError::StudentHasNotApplied);
-        }
-
-        let status = if approved {
-            String::from_str(&env, APPLICATION_STATUS_APPROVED)
-        } else {
-            String::from_str(&env, APPLICATION_STATUS_REJECTED)
-        };
-        Self::set_application_status(env.clone(), pool_id, student.clone(), status);
-
-        // Issue #954: emit application-approved event
-        env.events()
-            .publish((APP_APPROVED, pool_id), (student.clone(), approved));
     }
 
-    /// Set application milestones and enforce sum(amounts) == pool goal.
-    pub fn setup_application_milestones(
-        env: Env,
-        pool_id: u32,
-        student: Address,
-        milestones: Vec<Milestone>,
-    ) {
-        student.require_auth();
-
-        let pool: Pool = env
+    /// Set or move the end of the school review window.
+    ///
+    /// Only the pool sponsor may call this. The review deadline must be
+    /// after both the application deadline and the current ledger timestamp;
+    /// `approve_application()` is rejected once it has passed. The change is
+    /// recorded in the pool's parameter history (an unset value reads as 0)
+    /// and `REVIEW_DEADLINE_SET` is emitted with `(old, new)`.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidDeadline` if `review_deadline` is not after the
+    ///   application deadline or is not in the future
+    pub fn set_review_deadline(env: Env, pool_id: u32, review_deadline: u64) {
+        let mut pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        if milestones.is_empty() {
-            panic!("Milestones required");
-        }
+        pool.sponsor.require_auth();
 
-        let mut sum: u128 = 0;
-        for i in 0..milestones.len() {
-            sum = sum
-                .checked_add(milestones.get(i).unwrap().amount)
-                .expect("Milestone amount overflow");
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
         }
-
-        if sum != pool.goal {
-            panic!("Milestone total must equal pool goal");
+        if review_deadline <= pool.application_deadline
+            || review_deadline <= env.ledger().timestamp()
+        {
+            env.panic_with_error(ContractError::InvalidDeadline);
         }
 
-        let milestones_key = (Symbol::new(&env, MILESTONES_PREFIX), pool_id, student.clone());
-        env.storage().persistent().set(&milestones_key, &milestones);
-
-        // Issue #954: emit milestones-set event
-        env.events()
-            .publish((MILESTONES_SET, pool_id), (student.clone(), milestones.len()));
-    }
-
-    /// Get student milestones for a pool.
-    pub fn get_milestones(env: Env, pool_id: u32, student: Address) -> Vec<Milestone> {
-        let milestones_key = (Symbol::new(&env, MILESTONES_PREFIX), pool_id, student);
-        env.storage()
-            .persistent()
-            .get::<_, Vec<Milestone>>(&milestones_key)
-            .unwrap_or(Vec::new(&env))
-    }
+        let old_deadline = pool.review_deadline.unwrap_or(0);
+        pool.review_deadline = Some(review_deadline);
+        env.storage().persistent().set(&pool_id, &pool);
 
-    /// Set application status for a student in a pool.
-    pub fn set_application_status(env: Env, pool_id: u32, student: Address, status: String) {
-        let status_key = (
-            Symbol::new(&env, APPLICATION_STATUS_PREFIX),
+        Self::record_parameter_change(
+            &env,
             pool_id,
-            student.clone(),
+            PoolParameter::ReviewDeadline,
+            old_deadline as u128,
+            review_deadline as u128,
         );
-        env.storage().persistent().set(&status_key, &status);
-    }
-
-    /// Get application status for a student in a pool.
-    pub fn get_application_status(env: Env, pool_id: u32, student: Address) -> String {
-        let status_key = (
-            Symbol::new(&env, APPLICATION_STATUS_PREFIX),
-            pool_id,
-            student.clone(),
+        env.events().publish(
+            (REVIEW_DEADLINE_SET, pool_id),
+            (old_deadline, review_deadline),
         );
-        env.storage()
-            .persistent()
-            .get::<_, String>(&status_key)
-            .unwrap_or(String::from_str(&env, ""))
     }
 
-    /// Get claimed amount for a student in a pool.
-    pub fn get_claimed_amount(env: Env, pool_id: u32, student: Address) -> i128 {
-        let claimed_key = (
-            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
-            pool_id,
-            student.clone(),
-        );
-        // Try to get the Application struct first
-        let app: Option<Application> = env.storage().persistent().get(&claimed_key);
-        match app {
-            Some(application) => application.amount_claimed,
-            None => 0,
-        }
-    }
+    /// Get the end of a pool's review window, if one has been set.
+    pub fn get_review_deadline(env: Env, pool_id: u32) -> Option<u64> {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-    /// Get the full Application record for a student in a pool.
-    /// Returns `None` if the student has not yet made any claim.
-    pub fn get_application(env: Env, pool_id: u32, student: Address) -> Option<Application> {
-        let app_key = (
-            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
-            pool_id,
-            student.clone(),
-        );
-        env.storage().persistent().get::<_, Application>(&app_key)
+        pool.review_deadline
     }
 
-    /// Withdraw surplus funds not locked by active applications.
+    /// Get the phase a pool is in at the current ledger timestamp.
     ///
-    /// Locked funds = sum of (approved_amount - amount_claimed) for every
-    /// application whose status is "Approved" or "Pending".
-    /// Surplus = pool.collected - locked_funds.
-    ///
-    /// # Panics
-    /// - `ContractError::PoolNotFound` if pool_id is invalid
-    /// - `"Insolvency: locked funds exceed collected"` if locked > collected
-    /// - `"No surplus to withdraw"` if surplus == 0
-    pub fn withdraw_unallocated_funds(env: Env, pool_id: u32, token_address: Address) {
-        let mut pool: Pool = env
+    /// - `Ended`: the pool is `Cancelled` or closed, whatever the timestamp
+    /// - `Fundraising` / `Applications`: up to and including the application
+    ///   deadline, split on whether fundraising has ended (`Completed`)
+    /// - `Review`: after the application deadline, up to and including the
+    ///   review deadline
+    /// - `Disbursement`: after the review deadline (or straight after the
+    ///   application deadline when no review window is set), or whenever the
+    ///   pool is `Disbursed`
+    pub fn get_pool_phase(env: Env, pool_id: u32) -> PoolPhase {
+        let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
 
-        pool.sponsor.require_auth();
-
-        let count_key = (Symbol::new(&env, APPLICATION_COUNT_PREFIX), pool_id);
-        let app_count: u32 = env
-            .storage()
-            .persistent()
-            .get::<_, u32>(&count_key)
-            .unwrap_or(0);
+        if pool.is_closed || matches!(pool.state, PoolState::Cancelled | PoolState::Closed) {
+            return PoolPhase::Ended;
+        }
 
-        let approved_str = String::from_str(&env, APPLICATION_STATUS_APPROVED);
-        let pending_str = String::from_str(&env, "Pending");
-
-        let mut locked: u128 = 0u128;
-        for idx in 1..=app_count {
-            let app_key = (Symbol::new(&env, APPLICATION_PREFIX), pool_id, idx);
-            let entry: Option<(u32, Address, soroban_sdk::String)> =
-                env.storage().persistent().get(&app_key);
-            if let Some((_, student, _)) = entry {
-                let status_key = (
-                    Symbol::new(&env, APPLICATION_STATUS_PREFIX),
-                    pool_id,
-                    student.clone(),
-                );
-                let status: String = env
-                    .storage()
-                    .persistent()
-                    .get::<_, String>(&status_key)
-                    .unwrap_or(String::from_str(&env, ""));
-
-                if status == approved_str || status == pending_str {
-                    let claim_key = (
-                        Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
-                        pool_id,
-                        student.clone(),
-                    );
-                    let application: Application = env
-                        .storage()
-                        .persistent()
-                        .get::<_, Application>(&claim_key)
-                        .unwrap_or(Application {
-                            approved_amount: 0,
-                            amount_claimed: 0,
-                        });
-                    let remaining =
-                        (application.approved_amount - application.amount_claimed).max(0) as u128;
-                    locked = locked
-                        .checked_add(remaining)
-                        .expect("Locked funds overflow");
-                }
+        let now = env.ledger().timestamp();
+        if pool.state == PoolState::Disbursed {
+            PoolPhase::Disbursement
+        } else if now <= pool.application_deadline {
+            if pool.state == PoolState::Completed {
+                PoolPhase::Applications
+            } else {
+                PoolPhase::Fundraising
             }
+        } else if pool.review_deadline.is_some_and(|review| now <= review) {
+            PoolPhase::Review
+        } else {
+            PoolPhase::Disbursement
         }
+    }
 
-        let surplus: u128 = pool
-            .collected
-            .checked_sub(locked)
-            .expect("Insolvency: locked funds exceed collected");
+    /// Return every sponsor change to the pool's goal, application deadline
+    /// and review deadline, oldest first.
+    pub fn get_parameter_history(env: Env, pool_id: u32) -> Vec<ParameterChange> {
+        let count_key = (Symbol::new(&env, PARAM_HISTORY_COUNT_PREFIX), pool_id);
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
 
-        if surplus == 0 {
-            panic!("No surplus to withdraw");
+        let mut history = Vec::new(&env);
+        for i in 0..count {
+            let entry_key = (Symbol::new(&env, PARAM_HISTORY_PREFIX), pool_id, i);
+            if let Some(change) = env
+                .storage()
+                .persistent()
+                .get::<_, ParameterChange>(&entry_key)
+            {
+                history.push_back(change);
+            }
         }
+        history
+    }
 
-        let token_client = token::Client::new(&env, &token_address);
+    // Note: try_get_pool is auto-generated by Soroban SDK from get_pool
+    // Commenting out manual implementation to avoid duplicate definition
+    // /// Safely retrieve pool information.
+    // pub fn try_get_pool(env: Env, pool_id: u32) -> Option<(u32, Address, u128, u128, bool)> {
+    //     env.storage()
+    //         .persistent()
+    //         .get::<_, Pool>(&pool_id)
+    //         .map(|pool| {
+    //             (
+    //                 pool_id,
+    //                 pool.sponsor,
+    //                 pool.goal,
+    //                 pool.collected,
+    //                 pool.is_closed,
+    //             )
+    //         })
+    // }
+
+    /// Get the total amount raised for a pool.
+    pub fn get_total_raised(env: Env, pool_id: u32) -> u128 {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.collected
+    }
+
+    /// Close a donation pool.
+    ///
+    /// Moves a `Disbursed` or `Cancelled` pool to `Closed` and sets
+    /// `is_closed`. Only the sponsor may close a pool.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolNotDisbursedOrRefunded` if the pool is in any other state
+    pub fn close_pool(env: Env, pool_id: u32) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+
+        if pool.state != PoolState::Disbursed && pool.state != PoolState::Cancelled {
+            env.panic_with_error(ContractError::PoolNotDisbursedOrRefunded);
+        }
+
+        let sponsor = pool.sponsor.clone();
+        let mut updated_pool =
+            Self::transition_pool(&env, pool_id, pool, PoolState::Closed, &sponsor);
+        updated_pool.is_closed = true;
+
+        env.storage().persistent().set(&pool_id, &updated_pool);
+
+        // Emit pool closed event
+        env.events().publish(
+            (POOL_CLOSED, pool_id),
+            (updated_pool.sponsor.clone(), updated_pool.collected),
+        );
+    }
+
+    /// Get the total number of pools.
+    pub fn get_pool_count(env: Env) -> u32 {
+        let pool_count_key = Symbol::new(&env, POOL_COUNT);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&pool_count_key)
+            .unwrap_or(0)
+    }
+
+    /// Get the number of unique donors for a pool.
+    pub fn get_donor_count(env: Env, pool_id: u32) -> u32 {
+        // Verify the pool exists first
+        let _pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&(pool_id, "d_count"))
+            .unwrap_or(0)
+    }
+
+    /// Get the number of donations recorded for a pool.
+    pub fn get_donation_count(env: Env, pool_id: u32) -> u32 {
+        let count_key = (Symbol::new(&env, DONATION_COUNT_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&count_key)
+            .unwrap_or(0)
+    }
+
+    /// Get a page of a pool's donation entries, oldest first.
+    ///
+    /// `start` is the zero-based index of the first entry; at most
+    /// `min(limit, MAX_PAGE_SIZE)` entries are returned.
+    pub fn get_donations(env: Env, pool_id: u32, start: u32, limit: u32) -> Vec<DonationEntry> {
+        let _pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        let count = Self::get_donation_count(env.clone(), pool_id);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut page = Vec::new(&env);
+        for idx in start..end {
+            let entry_key = (Symbol::new(&env, DONATION_PREFIX), pool_id, idx);
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<_, DonationEntry>(&entry_key)
+            {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Get the number of donations a donor has made across all pools.
+    pub fn get_donor_history_count(env: Env, donor: Address) -> u32 {
+        let count_key = (Symbol::new(&env, DONOR_HISTORY_COUNT_PREFIX), donor);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&count_key)
+            .unwrap_or(0)
+    }
+
+    /// Get a page of the donations a donor has made, across all pools,
+    /// oldest first.
+    ///
+    /// `start` is the zero-based index of the first entry; at most
+    /// `min(limit, MAX_PAGE_SIZE)` entries are returned.
+    pub fn get_donor_history(
+        env: Env,
+        donor: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DonationEntry> {
+        let count = Self::get_donor_history_count(env.clone(), donor.clone());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut history = Vec::new(&env);
+        for i in start..end {
+            let ref_key = (Symbol::new(&env, DONOR_HISTORY_PREFIX), donor.clone(), i);
+            let (pool_id, idx): (u32, u32) = env
+                .storage()
+                .persistent()
+                .get(&ref_key)
+                .expect("Donor history entry missing");
+            let entry_key = (Symbol::new(&env, DONATION_PREFIX), pool_id, idx);
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<_, DonationEntry>(&entry_key)
+            {
+                history.push_back(entry);
+            }
+        }
+        history
+    }
+
+    /// Get the total contribution of a specific donor to a specific pool.
+    pub fn get_contribution(env: Env, pool_id: u32, donor: Address) -> u128 {
+        // Verify the pool exists first
+        let _pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        env.storage()
+            .persistent()
+            .get::<_, u128>(&(pool_id, "contribution", &donor))
+            .unwrap_or(0)
+    }
+
+    /// Student applies to a school-linked pool.
+    ///
+    /// If the pool requires attestations, the student must hold an unexpired
+    /// attestation of each required kind from the pool's linked school.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` unless the pool is `Active` or `Completed`
+    /// - `ContractError::AttestationMissing` if a required attestation is
+    ///   missing or expired
+    /// - `ContractError::DocumentUriTooLong` if the document URI exceeds `MAX_URL_LENGTH`
+    /// - `ContractError::SummaryTooLong` if the summary exceeds `MAX_SUMMARY_LENGTH`
+    pub fn apply_to_pool(env: Env, pool_id: u32, student: Address, payload: ApplicationPayload) {
+        student.require_auth();
+
+        if payload.document_uri.len() > MAX_URL_LENGTH as u32 {
+            env.panic_with_error(ContractError::DocumentUriTooLong);
+        }
+        if payload.summary.len() > MAX_SUMMARY_LENGTH as u32 {
+            env.panic_with_error(ContractError::SummaryTooLong);
+        }
+
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_open_for_awards(&env, &pool);
+
+        if env.ledger().timestamp() > pool.application_deadline {
+            env.panic_with_error(ContractError::ApplicationDeadlinePassed);
+        }
+
+        let applicant_key = (
+            Symbol::new(&env, APPLICANT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        if env.storage().persistent().has(&applicant_key) {
+            env.panic_with_error(ContractError::DuplicateApplication);
+        }
+
+        let required = Self::get_required_attestations(env.clone(), pool_id);
+        if !required.is_empty() {
+            let school = Self::get_pool_school(env.clone(), pool_id);
+            let now = env.ledger().timestamp();
+            for kind in required.iter() {
+                let valid =
+                    Self::get_attestation(env.clone(), school.clone(), student.clone(), kind)
+                        .is_some_and(|attestation| now < attestation.expires_at);
+                if !valid {
+                    env.panic_with_error(ContractError::AttestationMissing);
+                }
+            }
+        }
+
+        let count_key = (Symbol::new(&env, APPLICATION_COUNT_PREFIX), pool_id);
+        let mut app_count: u32 = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&count_key)
+            .unwrap_or(0);
+        app_count += 1;
+
+        let app_key = (Symbol::new(&env, APPLICATION_PREFIX), pool_id, app_count);
+        env.storage()
+            .persistent()
+            .set(&app_key, &(app_count, student.clone(), payload));
+
+        env.storage().persistent().set(&applicant_key, &app_count);
+        env.storage().persistent().set(&count_key, &app_count);
+
+        Self::write_application_status(&env, pool_id, &student, ApplicationStatus::Pending);
+
+        // Emit application/contribution event with privacy flag (default: false for public)
+        env.events().publish(
+            (APPLICATION_SUBMITTED, pool_id),
+            (student.clone(), app_count, false), // false = public application
+        );
+    }
+
+    /// School approves or rejects a student's application.
+    ///
+    /// Only `Pending` applications can be decided. Closed once the pool's
+    /// review deadline, if set, has passed. On approval the student's
+    /// [`Application`] is stored with `approved_amount` as the most they
+    /// may claim, and that amount is reserved from the pool; the amount is
+    /// ignored on rejection. If the pool already has `max_recipients`
+    /// approved students, the application is `Waitlisted` instead and
+    /// nothing is reserved until it is promoted.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` unless the pool is `Active` or `Completed`
+    /// - `ContractError::InvalidApprovedAmount` if approving with
+    ///   `approved_amount <= 0`
+    /// - `ContractError::InsufficientUnreservedFunds` if the pool's
+    ///   unreserved balance is below `approved_amount`
+    pub fn approve_application(
+        env: Env,
+        pool_id: u32,
+        school: Address,
+        student: Address,
+        approved: bool,
+        approved_amount: i128,
+    ) {
+        school.require_auth();
+
+        let linked_school = Self::get_pool_school(env.clone(), pool_id);
+        if linked_school != school {
+            env.panic_with_error(ContractError::OnlyLinkedSchoolCanApprove);
+        }
+
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_open_for_awards(&env, &pool);
+        if pool
+            .review_deadline
+            .is_some_and(|review| env.ledger().timestamp() > review)
+        {
+            env.panic_with_error(ContractError::ReviewWindowClosed);
+        }
+
+        let applicant_key = (
+            Symbol::new(&env, APPLICANT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        if !env.storage().persistent().has(&applicant_key) {
+            env.panic_with_error(ContractError::StudentHasNotApplied);
+        }
+
+        if approved && approved_amount <= 0 {
+            env.panic_with_error(ContractError::InvalidApprovedAmount);
+        }
+
+        let status = if approved {
+            Self::admission_status(&env, pool_id, &pool)
+        } else {
+            ApplicationStatus::Rejected
+        };
+        Self::transition_application(&env, pool_id, &student, status.clone());
+
+        let approved_amount = if approved { approved_amount } else { 0 };
+        if approved {
+            Self::admit_application(&env, pool_id, &student, status, approved_amount);
+        } else {
+            let rejected_at_key = (
+                Symbol::new(&env, REJECTED_AT_PREFIX),
+                pool_id,
+                student.clone(),
+            );
+            env.storage()
+                .persistent()
+                .set(&rejected_at_key, &env.ledger().timestamp());
+        }
+
+        // Issue #954: emit application-approved event
+        env.events().publish(
+            (APP_APPROVED, pool_id),
+            (student.clone(), approved, approved_amount),
+        );
+    }
+
+    /// School lowers a student's approved amount before any funds are claimed.
+    ///
+    /// The difference is released from the pool's reservation. Emits
+    /// `APPROVED_AMOUNT_ADJUSTED` with `(student, old_amount, new_amount)`.
+    ///
+    /// # Panics
+    /// - `ContractError::OnlyLinkedSchoolCanApprove` if `school` is not linked to the pool
+    /// - `ContractError::InvalidStatusTransition` if the application is not `Approved`
+    /// - `ContractError::ClaimAlreadyMade` if the student has claimed any funds
+    /// - `DisbursementError::MilestoneTotalMismatch` if the student has an
+    ///   active or proposed milestone plan sized to the current amount
+    /// - `ContractError::InvalidApprovedAmount` unless `0 < new_amount < approved_amount`
+    pub fn adjust_approved_amount(
+        env: Env,
+        pool_id: u32,
+        school: Address,
+        student: Address,
+        new_amount: i128,
+    ) {
+        school.require_auth();
+
+        let linked_school = Self::get_pool_school(env.clone(), pool_id);
+        if linked_school != school {
+            env.panic_with_error(ContractError::OnlyLinkedSchoolCanApprove);
+        }
+
+        let status = Self::read_application_status(&env, pool_id, &student)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::StudentHasNotApplied));
+        if status != ApplicationStatus::Approved {
+            env.panic_with_error(ContractError::InvalidStatusTransition);
+        }
+
+        let app_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let mut application: Application = env
+            .storage()
+            .persistent()
+            .get::<_, Application>(&app_key)
+            .unwrap_or_else(|| panic!("Application not found"));
+        if application.amount_claimed > 0 {
+            env.panic_with_error(ContractError::ClaimAlreadyMade);
+        }
+        let milestones_key = (
+            Symbol::new(&env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let proposed_key = (
+            Symbol::new(&env, PROPOSED_MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        if env.storage().persistent().has(&milestones_key)
+            || env.storage().persistent().has(&proposed_key)
+        {
+            env.panic_with_error(DisbursementError::MilestoneTotalMismatch);
+        }
+        if new_amount <= 0 || new_amount >= application.approved_amount {
+            env.panic_with_error(ContractError::InvalidApprovedAmount);
+        }
+
+        let old_amount = application.approved_amount;
+        application.approved_amount = new_amount;
+        env.storage().persistent().set(&app_key, &application);
+        Self::release_reservation(&env, pool_id, (old_amount - new_amount) as u128);
+        Self::promote_waitlisted(&env, pool_id);
+
+        env.events().publish(
+            (APPROVED_AMOUNT_ADJUSTED, pool_id),
+            (student, old_amount, new_amount),
+        );
+    }
+
+    /// Propose a milestone plan for an approved student.
+    ///
+    /// The plan only takes effect once the pool's linked school approves it
+    /// with `approve_milestone_plan`; a new proposal replaces any earlier one
+    /// still awaiting approval. Amounts must add up to the student's
+    /// `approved_amount`, and every milestone must be `Pending`, have a
+    /// positive amount and be due after the current ledger timestamp.
+    ///
+    /// # Panics
+    /// - `DisbursementError::ApplicationNotApproved` if the student is not `Approved`
+    /// - `DisbursementError::MilestonePlanFrozen` if a milestone has already been paid
+    /// - `ContractError::ClaimAlreadyMade` if the student has claimed any funds
+    /// - `ContractError::InvalidMilestonePlan` if a milestone breaks those rules
+    /// - `DisbursementError::MilestoneTotalMismatch` if the amounts do not add up
+    ///   to `approved_amount`
+    pub fn setup_application_milestones(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+        milestones: Vec<Milestone>,
+    ) {
+        student.require_auth();
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        Self::ensure_milestone_plan_open(&env, pool_id, &student);
+        if application.amount_claimed > 0 {
+            env.panic_with_error(ContractError::ClaimAlreadyMade);
+        }
+
+        let sum = Self::validate_new_milestones(&env, &milestones);
+        if sum != application.approved_amount as u128 {
+            env.panic_with_error(DisbursementError::MilestoneTotalMismatch);
+        }
+
+        let proposed_key = (
+            Symbol::new(&env, PROPOSED_MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&proposed_key, &milestones);
+
+        env.events().publish(
+            (MILESTONES_PROPOSED, pool_id),
+            (student.clone(), milestones.len()),
+        );
+    }
+
+    /// Linked school approves a student's proposed milestone plan, making it
+    /// the plan `claim_funds` enforces.
+    ///
+    /// # Panics
+    /// - `ContractError::OnlyLinkedSchoolCanApprove` if `school` is not linked to the pool
+    /// - `DisbursementError::MilestonePlanNotFound` if no plan is awaiting approval
+    /// - `DisbursementError::MilestonePlanFrozen` if a milestone has already been paid
+    /// - `DisbursementError::ApplicationNotApproved` if the student is no longer `Approved`
+    /// - `ContractError::ClaimAlreadyMade` if the student has claimed any funds
+    /// - `DisbursementError::MilestoneTotalMismatch` if the approved amount has
+    ///   changed since the plan was proposed
+    pub fn approve_milestone_plan(env: Env, pool_id: u32, school: Address, student: Address) {
+        school.require_auth();
+
+        let linked_school = Self::get_pool_school(env.clone(), pool_id);
+        if linked_school != school {
+            env.panic_with_error(ContractError::OnlyLinkedSchoolCanApprove);
+        }
+
+        let proposed_key = (
+            Symbol::new(&env, PROPOSED_MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let milestones: Vec<Milestone> = env
+            .storage()
+            .persistent()
+            .get(&proposed_key)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::MilestonePlanNotFound));
+        Self::ensure_milestone_plan_open(&env, pool_id, &student);
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        if application.amount_claimed > 0 {
+            env.panic_with_error(ContractError::ClaimAlreadyMade);
+        }
+        let sum: u128 = milestones.iter().map(|milestone| milestone.amount).sum();
+        if sum != application.approved_amount as u128 {
+            env.panic_with_error(DisbursementError::MilestoneTotalMismatch);
+        }
+
+        Self::activate_milestone_plan(&env, pool_id, &student, &milestones);
+        env.storage().persistent().remove(&proposed_key);
+
+        // Issue #954: emit milestones-set event
+        env.events().publish(
+            (MILESTONES_SET, pool_id),
+            (student.clone(), milestones.len()),
+        );
+    }
+
+    /// Sponsor decides whether milestone amendments in this pool also need
+    /// the sponsor's consent. Applies to amendments proposed afterwards.
+    /// Emits `AMENDMENT_SPONSOR_SET` with `required`.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if the pool does not exist
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    pub fn set_amendment_sponsor_consent(env: Env, pool_id: u32, required: bool) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+
+        let sponsor_key = (Symbol::new(&env, AMENDMENT_SPONSOR_PREFIX), pool_id);
+        env.storage().persistent().set(&sponsor_key, &required);
+
+        env.events()
+            .publish((AMENDMENT_SPONSOR_SET, pool_id), required);
+    }
+
+    /// Whether milestone amendments in this pool need the sponsor's consent.
+    pub fn get_amendment_sponsor_consent(env: Env, pool_id: u32) -> bool {
+        let sponsor_key = (Symbol::new(&env, AMENDMENT_SPONSOR_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&sponsor_key)
+            .unwrap_or(false)
+    }
+
+    /// Student or linked school proposes new milestones to replace the
+    /// unpaid part of the student's active plan.
+    ///
+    /// `Paid` milestones are kept in front of `milestones`, and the amended
+    /// plan must still total the approved amount. The proposer's consent is
+    /// recorded straight away; the other party, and the sponsor when the pool
+    /// requires it, consent with `consent_milestone_amendment`. A new proposal
+    /// replaces one still awaiting consent. Emits `AMENDMENT_PROPOSED` with
+    /// `(student, proposer, milestones.len())`.
+    ///
+    /// # Panics
+    /// - `DisbursementError::NotAmendmentParty` if `proposer` is neither the
+    ///   student nor the linked school
+    /// - `DisbursementError::ApplicationNotApproved` if the student is not `Approved`
+    /// - `DisbursementError::MilestonePlanNotFound` if the student has no active plan
+    /// - `ContractError::InvalidMilestonePlan` if a new milestone is not
+    ///   `Pending`, has no amount or is already due
+    /// - `DisbursementError::MilestoneTotalMismatch` if the amended total is
+    ///   not the approved amount
+    pub fn propose_milestone_amendment(
+        env: Env,
+        pool_id: u32,
+        proposer: Address,
+        student: Address,
+        milestones: Vec<Milestone>,
+    ) {
+        proposer.require_auth();
+
+        let is_student = proposer == student;
+        let is_school = proposer == Self::get_pool_school(env.clone(), pool_id);
+        if !is_student && !is_school {
+            env.panic_with_error(DisbursementError::NotAmendmentParty);
+        }
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        Self::validate_new_milestones(&env, &milestones);
+        Self::amended_plan(&env, pool_id, &student, &application, &milestones);
+
+        let amendment = MilestoneAmendment {
+            proposer: proposer.clone(),
+            milestones: milestones.clone(),
+            student_consent: is_student,
+            school_consent: is_school,
+            sponsor_required: Self::get_amendment_sponsor_consent(env.clone(), pool_id),
+            sponsor_consent: false,
+        };
+        let amendment_key = (
+            Symbol::new(&env, AMENDMENT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&amendment_key, &amendment);
+
+        env.events().publish(
+            (AMENDMENT_PROPOSED, pool_id),
+            (student, proposer, milestones.len()),
+        );
+    }
+
+    /// Student, linked school or sponsor consents to the pending amendment.
+    ///
+    /// Once the student, the school and, if required, the sponsor have all
+    /// consented, the amended plan becomes the active plan under the next
+    /// version number. Emits `AMENDMENT_CONSENTED` with `(student, party)`,
+    /// then `AMENDMENT_APPLIED` with `(student, version)` when it takes effect.
+    /// Returns whether the amendment was applied.
+    ///
+    /// # Panics
+    /// - `DisbursementError::AmendmentNotFound` if no amendment awaits consent
+    /// - `DisbursementError::NotAmendmentParty` if `party` is not the student,
+    ///   linked school or pool sponsor
+    /// - `DisbursementError::ApplicationNotApproved` or
+    ///   `DisbursementError::MilestoneTotalMismatch` if the amendment no longer
+    ///   fits the student's award when it would be applied
+    pub fn consent_milestone_amendment(
+        env: Env,
+        pool_id: u32,
+        party: Address,
+        student: Address,
+    ) -> bool {
+        party.require_auth();
+
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        let amendment_key = (
+            Symbol::new(&env, AMENDMENT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let mut amendment: MilestoneAmendment = env
+            .storage()
+            .persistent()
+            .get(&amendment_key)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::AmendmentNotFound));
+
+        let mut is_party = false;
+        if party == student {
+            amendment.student_consent = true;
+            is_party = true;
+        }
+        if party == Self::get_pool_school(env.clone(), pool_id) {
+            amendment.school_consent = true;
+            is_party = true;
+        }
+        if party == pool.sponsor {
+            amendment.sponsor_consent = true;
+            is_party = true;
+        }
+        if !is_party {
+            env.panic_with_error(DisbursementError::NotAmendmentParty);
+        }
+
+        env.events()
+            .publish((AMENDMENT_CONSENTED, pool_id), (student.clone(), party));
+
+        let agreed = amendment.student_consent
+            && amendment.school_consent
+            && (!amendment.sponsor_required || amendment.sponsor_consent);
+        if !agreed {
+            env.storage().persistent().set(&amendment_key, &amendment);
+            return false;
+        }
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        let milestones =
+            Self::amended_plan(&env, pool_id, &student, &application, &amendment.milestones);
+        let version = Self::activate_milestone_plan(&env, pool_id, &student, &milestones);
+        env.storage().persistent().remove(&amendment_key);
+
+        env.events()
+            .publish((AMENDMENT_APPLIED, pool_id), (student, version));
+        true
+    }
+
+    /// Get the milestone amendment awaiting consent for a student, if any.
+    pub fn get_milestone_amendment(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+    ) -> Option<MilestoneAmendment> {
+        let amendment_key = (Symbol::new(&env, AMENDMENT_PREFIX), pool_id, student);
+        env.storage()
+            .persistent()
+            .get::<_, MilestoneAmendment>(&amendment_key)
+    }
+
+    /// Version number of the student's active milestone plan; 0 if none
+    /// has been approved yet.
+    pub fn get_milestone_plan_version(env: Env, pool_id: u32, student: Address) -> u32 {
+        let version_key = (Symbol::new(&env, PLAN_VERSION_PREFIX), pool_id, student);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&version_key)
+            .unwrap_or(0)
+    }
+
+    /// Get a version of the student's milestone plan as it was when it
+    /// became active.
+    pub fn get_milestone_plan_at(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+        version: u32,
+    ) -> Option<Vec<Milestone>> {
+        let history_key = (
+            Symbol::new(&env, PLAN_HISTORY_PREFIX),
+            pool_id,
+            student,
+            version,
+        );
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Milestone>>(&history_key)
+    }
+
+    /// Student submits evidence that a `Pending` milestone was reached,
+    /// moving it to `Submitted` for the linked school to verify.
+    ///
+    /// A milestone the school turned down returns to `Pending` and may be
+    /// submitted again before it falls due. Emits `MILESTONE_SUBMITTED` with
+    /// `(student, index, evidence_hash)`.
+    ///
+    /// # Panics
+    /// - `DisbursementError::MilestoneNotFound` if `index` is not in the active plan
+    /// - `DisbursementError::InvalidMilestoneStatus` if the milestone is not
+    ///   `Pending` (including when it has expired)
+    pub fn submit_milestone_evidence(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+        index: u32,
+        evidence_hash: BytesN<32>,
+    ) {
+        student.require_auth();
+
+        let mut milestones = Self::get_milestones(env.clone(), pool_id, student.clone());
+        let mut milestone = milestones
+            .get(index)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::MilestoneNotFound));
+        if milestone.status != MilestoneStatus::Pending {
+            env.panic_with_error(DisbursementError::InvalidMilestoneStatus);
+        }
+        milestone.status = MilestoneStatus::Submitted;
+        milestones.set(index, milestone);
+
+        let milestones_key = (
+            Symbol::new(&env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&milestones_key, &milestones);
+        let evidence_key = (
+            Symbol::new(&env, MILESTONE_EVIDENCE_PREFIX),
+            pool_id,
+            student.clone(),
+            index,
+        );
+        env.storage()
+            .persistent()
+            .set(&evidence_key, &evidence_hash);
+
+        env.events().publish(
+            (MILESTONE_SUBMITTED, pool_id),
+            (student, index, evidence_hash),
+        );
+    }
+
+    /// Linked school rules on a `Submitted` milestone.
+    ///
+    /// An accepted milestone becomes `Verified`, and the verified milestones
+    /// the student could claim are then paid out straight away, exactly as
+    /// if the student had called `claim_funds`. Milestones not yet covered by
+    /// the student's vesting schedule stay `Verified` until they are claimed.
+    /// A turned-down milestone returns to `Pending`. Emits
+    /// `MILESTONE_VERIFIED` with `(student, index, accepted)` and, on payout,
+    /// `MILESTONE_PAID` for each milestone paid alongside `FUNDS_CLAIMED`.
+    ///
+    /// # Panics
+    /// - `ContractError::OnlyLinkedSchoolCanApprove` if `school` is not linked to the pool
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` unless the pool is `Active` or `Completed`
+    /// - `DisbursementError::ApplicationNotApproved` if the student is no longer `Approved`
+    /// - `DisbursementError::MilestoneNotFound` if `index` is not in the active plan
+    /// - `DisbursementError::InvalidMilestoneStatus` if the milestone is not `Submitted`
+    pub fn verify_milestone(
+        env: Env,
+        pool_id: u32,
+        school: Address,
+        student: Address,
+        index: u32,
+        accepted: bool,
+    ) {
+        school.require_auth();
+
+        let linked_school = Self::get_pool_school(env.clone(), pool_id);
+        if linked_school != school {
+            env.panic_with_error(ContractError::OnlyLinkedSchoolCanApprove);
+        }
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_open_for_awards(&env, &pool);
+        let mut application = Self::approved_application(&env, pool_id, &student);
+
+        let mut milestones = Self::get_milestones(env.clone(), pool_id, student.clone());
+        let mut milestone = milestones
+            .get(index)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::MilestoneNotFound));
+        if milestone.status != MilestoneStatus::Submitted {
+            env.panic_with_error(DisbursementError::InvalidMilestoneStatus);
+        }
+        milestone.status = if accepted {
+            MilestoneStatus::Verified
+        } else {
+            MilestoneStatus::Pending
+        };
+        milestones.set(index, milestone);
+        let milestones_key = (
+            Symbol::new(&env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&milestones_key, &milestones);
+
+        env.events().publish(
+            (MILESTONE_VERIFIED, pool_id),
+            (student.clone(), index, accepted),
+        );
+
+        let payable = Self::payable_verified_amount(&env, pool_id, &student, &application);
+        if accepted && payable > 0 {
+            Self::pay_verified_milestones(&env, pool_id, &student, payable as u128);
+            Self::disburse_claim(&env, pool_id, &student, &pool, &mut application, payable);
+        }
+    }
+
+    /// Get the evidence hash a student last submitted for a milestone, if any.
+    pub fn get_milestone_evidence(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+        index: u32,
+    ) -> Option<BytesN<32>> {
+        let evidence_key = (
+            Symbol::new(&env, MILESTONE_EVIDENCE_PREFIX),
+            pool_id,
+            student,
+            index,
+        );
+        env.storage()
+            .persistent()
+            .get::<_, BytesN<32>>(&evidence_key)
+    }
+
+    /// Get a student's milestone plan awaiting school approval, if any.
+    pub fn get_proposed_milestones(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+    ) -> Option<Vec<Milestone>> {
+        let proposed_key = (
+            Symbol::new(&env, PROPOSED_MILESTONES_PREFIX),
+            pool_id,
+            student,
+        );
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Milestone>>(&proposed_key)
+    }
+
+    /// Get student milestones for a pool.
+    ///
+    /// Milestones past their due timestamp that were never verified are
+    /// reported as `Expired`.
+    pub fn get_milestones(env: Env, pool_id: u32, student: Address) -> Vec<Milestone> {
+        let milestones_key = (Symbol::new(&env, MILESTONES_PREFIX), pool_id, student);
+        let mut milestones = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Milestone>>(&milestones_key)
+            .unwrap_or(Vec::new(&env));
+        Self::expire_overdue_milestones(&env, &mut milestones);
+        milestones
+    }
+
+    /// Student withdraws their own `Pending` or `Approved` application.
+    ///
+    /// Any unclaimed reservation is released back to the pool. Unless the
+    /// student has already claimed funds, the applicant marker and approval
+    /// are cleared, along with the active milestone plan and its evidence and
+    /// any proposed plan or pending amendment, so they may apply again before
+    /// the application deadline. Earlier plan versions stay queryable.
+    /// Emits `APP_WITHDRAWN` with `(student, released_amount)`.
+    ///
+    /// # Panics
+    /// - `ContractError::StudentHasNotApplied` if the student has no application
+    /// - `ContractError::InvalidStatusTransition` if the application is not
+    ///   `Pending` or `Approved`
+    pub fn withdraw_application(env: Env, pool_id: u32, student: Address) {
+        student.require_auth();
+
+        let was_approved = Self::read_application_status(&env, pool_id, &student)
+            == Some(ApplicationStatus::Approved);
+        let app_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let application = env.storage().persistent().get::<_, Application>(&app_key);
+
+        Self::transition_application(&env, pool_id, &student, ApplicationStatus::Withdrawn);
+
+        let released = match &application {
+            Some(app) if was_approved => (app.approved_amount - app.amount_claimed).max(0),
+            _ => 0,
+        };
+        if application
+            .as_ref()
+            .is_none_or(|app| app.amount_claimed == 0)
+        {
+            let applicant_key = (
+                Symbol::new(&env, APPLICANT_PREFIX),
+                pool_id,
+                student.clone(),
+            );
+            env.storage().persistent().remove(&applicant_key);
+            env.storage().persistent().remove(&app_key);
+            let vesting_key = (Symbol::new(&env, VESTING_PREFIX), pool_id, student.clone());
+            env.storage().persistent().remove(&vesting_key);
+            let payee_key = (Symbol::new(&env, PAYEE_PREFIX), pool_id, student.clone());
+            env.storage().persistent().remove(&payee_key);
+            Self::clear_milestone_state(&env, pool_id, &student);
+        }
+
+        env.events()
+            .publish((APP_WITHDRAWN, pool_id), (student, released));
+    }
+
+    /// Admin revokes an `Approved` application.
+    ///
+    /// The unclaimed part of the approval is released back to the pool.
+    ///
+    /// # Panics
+    /// - `ContractError::AdminNotSet` if no admin is configured
+    /// - `ContractError::UnauthorizedAdmin` if `admin` is not the stored admin
+    /// - `ContractError::StudentHasNotApplied` if the student has no application
+    /// - `ContractError::InvalidStatusTransition` if the application is not `Approved`
+    pub fn revoke_application(env: Env, pool_id: u32, admin: Address, student: Address) {
+        admin.require_auth();
+
+        let stored_admin = Self::read_admin(&env)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
+        if stored_admin != admin {
+            env.panic_with_error(ContractError::UnauthorizedAdmin);
+        }
+
+        Self::transition_application(&env, pool_id, &student, ApplicationStatus::Revoked);
+    }
+
+    /// Student appeals the rejection of their application.
+    ///
+    /// Each rejection can be appealed once, within `APPEAL_WINDOW_SECS` of
+    /// the rejection. Emits `APPEAL_FILED` with `(student, reason_hash)`.
+    ///
+    /// # Panics
+    /// - `ContractError::ApplicationNotRejected` if the application is not `Rejected`
+    /// - `ContractError::AppealAlreadyFiled` if the student has already appealed
+    /// - `ContractError::AppealWindowClosed` if the appeal window has ended
+    pub fn file_appeal(env: Env, pool_id: u32, student: Address, reason_hash: BytesN<32>) {
+        student.require_auth();
+
+        if Self::read_application_status(&env, pool_id, &student)
+            != Some(ApplicationStatus::Rejected)
+        {
+            env.panic_with_error(ContractError::ApplicationNotRejected);
+        }
+
+        let appeal_key = (Symbol::new(&env, APPEAL_PREFIX), pool_id, student.clone());
+        if env.storage().persistent().has(&appeal_key) {
+            env.panic_with_error(ContractError::AppealAlreadyFiled);
+        }
+
+        // Rejections recorded before appeals existed carry no timestamp and
+        // are treated as outside the window.
+        let rejected_at_key = (
+            Symbol::new(&env, REJECTED_AT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let now = env.ledger().timestamp();
+        let within_window = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&rejected_at_key)
+            .is_some_and(|rejected_at| now <= rejected_at.saturating_add(APPEAL_WINDOW_SECS));
+        if !within_window {
+            env.panic_with_error(ContractError::AppealWindowClosed);
+        }
+
+        let appeal = Appeal {
+            reason_hash: reason_hash.clone(),
+            filed_at: now,
+            status: AppealStatus::Open,
+        };
+        env.storage().persistent().set(&appeal_key, &appeal);
+
+        env.events()
+            .publish((APPEAL_FILED, pool_id), (student, reason_hash));
+    }
+
+    /// Admin or arbitrator rules on an open appeal.
+    ///
+    /// Granting the appeal approves (or, at capacity, waitlists) the
+    /// application for `approved_amount` like a school approval; denying it
+    /// leaves the application `Rejected`. Emits `APPEAL_RESOLVED` with
+    /// `(student, granted, approved_amount_or_0)`.
+    ///
+    /// # Panics
+    /// - `ContractError::UnauthorizedArbitrator` if `arbiter` is neither the
+    ///   admin nor the arbitrator
+    /// - `ContractError::AppealNotFound` if the student has not appealed
+    /// - `ContractError::AppealAlreadyResolved` if the appeal has been ruled on
+    /// - `ContractError::InvalidApprovedAmount` if granted with a non-positive amount
+    /// - `ContractError::InsufficientUnreservedFunds` if the pool cannot
+    ///   reserve `approved_amount`
+    pub fn resolve_appeal(
+        env: Env,
+        pool_id: u32,
+        arbiter: Address,
+        student: Address,
+        granted: bool,
+        approved_amount: i128,
+    ) {
+        arbiter.require_auth();
+
+        let is_admin = Self::read_admin(&env).is_some_and(|admin| admin == arbiter);
+        let is_arbitrator = Self::get_arbitrator(env.clone()).is_some_and(|a| a == arbiter);
+        if !is_admin && !is_arbitrator {
+            env.panic_with_error(ContractError::UnauthorizedArbitrator);
+        }
+
+        let appeal_key = (Symbol::new(&env, APPEAL_PREFIX), pool_id, student.clone());
+        let mut appeal: Appeal = env
+            .storage()
+            .persistent()
+            .get(&appeal_key)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AppealNotFound));
+        if appeal.status != AppealStatus::Open {
+            env.panic_with_error(ContractError::AppealAlreadyResolved);
+        }
+
+        let approved_amount = if granted { approved_amount } else { 0 };
+        if granted {
+            if approved_amount <= 0 {
+                env.panic_with_error(ContractError::InvalidApprovedAmount);
+            }
+            let pool: Pool = env
+                .storage()
+                .persistent()
+                .get::<_, Pool>(&pool_id)
+                .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+            let status = Self::admission_status(&env, pool_id, &pool);
+            // An open appeal implies the application is still `Rejected`
+            Self::write_application_status(&env, pool_id, &student, status.clone());
+            Self::admit_application(&env, pool_id, &student, status, approved_amount);
+            appeal.status = AppealStatus::Granted;
+        } else {
+            appeal.status = AppealStatus::Denied;
+        }
+        env.storage().persistent().set(&appeal_key, &appeal);
+
+        env.events().publish(
+            (APPEAL_RESOLVED, pool_id),
+            (student, granted, approved_amount),
+        );
+    }
+
+    /// Get a student's appeal, or `None` if they have not appealed.
+    pub fn get_appeal(env: Env, pool_id: u32, student: Address) -> Option<Appeal> {
+        let appeal_key = (Symbol::new(&env, APPEAL_PREFIX), pool_id, student);
+        env.storage().persistent().get::<_, Appeal>(&appeal_key)
+    }
+
+    /// Get a pool's recipient cap, or `None` if it has no cap.
+    pub fn get_max_recipients(env: Env, pool_id: u32) -> Option<u32> {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.max_recipients
+    }
+
+    /// Get the number of students currently `Approved` in a pool.
+    pub fn get_recipient_count(env: Env, pool_id: u32) -> u32 {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.recipient_count
+    }
+
+    /// Get a pool's waitlisted students in promotion order.
+    pub fn get_waitlist(env: Env, pool_id: u32) -> Vec<Address> {
+        let waitlist_key = (Symbol::new(&env, WAITLIST_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&waitlist_key)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get application status for a student in a pool.
+    /// Returns `None` if the student has no status in the pool.
+    pub fn get_application_status(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+    ) -> Option<ApplicationStatus> {
+        Self::read_application_status(&env, pool_id, &student)
+    }
+
+    /// Get the number of applications submitted to a pool, including
+    /// withdrawn ones and re-applications.
+    pub fn get_application_count(env: Env, pool_id: u32) -> u32 {
+        let count_key = (Symbol::new(&env, APPLICATION_COUNT_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&count_key)
+            .unwrap_or(0)
+    }
+
+    /// Get a page of a pool's applications, oldest first.
+    ///
+    /// `start` is the zero-based position of the first application to read;
+    /// at most `min(limit, MAX_PAGE_SIZE)` applications are read. When
+    /// `status_filter` is set, only applications with that status are
+    /// returned, so a page may hold fewer entries than were read. Advance
+    /// `start` by the page size to continue. An application superseded by the
+    /// student re-applying is reported as `Withdrawn`.
+    pub fn list_applications(
+        env: Env,
+        pool_id: u32,
+        start: u32,
+        limit: u32,
+        status_filter: Option<ApplicationStatus>,
+    ) -> Vec<ApplicationEntry> {
+        let _pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        let count = Self::get_application_count(env.clone(), pool_id);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut page = Vec::new(&env);
+        for idx in start..end {
+            let app_key = (Symbol::new(&env, APPLICATION_PREFIX), pool_id, idx + 1);
+            let Some((index, student, payload)) =
+                env.storage()
+                    .persistent()
+                    .get::<_, (u32, Address, ApplicationPayload)>(&app_key)
+            else {
+                continue;
+            };
+            let Some(mut status) = Self::read_application_status(&env, pool_id, &student) else {
+                continue;
+            };
+            let current = Self::application_index(&env, pool_id, &student);
+            if current != u32::MAX && current != index {
+                status = ApplicationStatus::Withdrawn;
+            }
+            if status_filter
+                .as_ref()
+                .is_some_and(|filter| *filter != status)
+            {
+                continue;
+            }
+            page.push_back(ApplicationEntry {
+                index,
+                student,
+                payload,
+                status,
+            });
+        }
+        page
+    }
+
+    /// Get claimed amount for a student in a pool.
+    pub fn get_claimed_amount(env: Env, pool_id: u32, student: Address) -> i128 {
+        let claimed_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        // Try to get the Application struct first
+        let app: Option<Application> = env.storage().persistent().get(&claimed_key);
+        match app {
+            Some(application) => application.amount_claimed,
+            None => 0,
+        }
+    }
+
+    /// Get the full Application record for a student in a pool.
+    /// Returns `None` if the student has not been approved.
+    pub fn get_application(env: Env, pool_id: u32, student: Address) -> Option<Application> {
+        let app_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().get::<_, Application>(&app_key)
+    }
+
+    /// Withdraw surplus funds not reserved for approved students.
+    ///
+    /// Surplus = pool balance - pool.reserved, where `reserved` is the sum of
+    /// (approved_amount - amount_claimed) over approved applications.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` if the pool is `Paused` or
+    ///   `Cancelled`; a cancelled pool's funds are left for donor refunds
+    /// - `ContractError::TokenMismatch` if `token_address` is not the pool token
+    /// - `"Insolvency: reserved funds exceed pool balance"` if reserved > balance
+    /// - `"No surplus to withdraw"` if surplus == 0
+    pub fn withdraw_unallocated_funds(env: Env, pool_id: u32, token_address: Address) {
+        let mut pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+        if matches!(pool.state, PoolState::Paused | PoolState::Cancelled) {
+            env.panic_with_error(ContractError::InvalidPoolState);
+        }
+        Self::ensure_pool_token(&env, &pool, &token_address);
+
+        let balance = Self::get_pool_balance(env.clone(), pool_id, token_address.clone());
+        let surplus: u128 = (balance.max(0) as u128)
+            .checked_sub(pool.reserved)
+            .expect("Insolvency: reserved funds exceed pool balance");
+
+        if surplus == 0 {
+            panic!("No surplus to withdraw");
+        }
+
+        Self::debit_pool_balance(&env, pool_id, &token_address, surplus as i128);
+
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &pool.sponsor,
+            &(surplus as i128),
+        );
+
+        pool.collected -= surplus;
+        env.storage().persistent().set(&pool_id, &pool);
+    }
+
+    /// Claim funds: allows an approved student to receive a partial or full
+    /// disbursement from a pool.
+    ///
+    /// Uses `Application` to persist `amount_claimed` across calls, enabling
+    /// streamed / milestone-based withdrawals where the student draws down
+    /// their approved allocation incrementally.
+    ///
+    /// If the student has a milestone plan, only `Verified` milestones can be
+    /// claimed: `claim_amount` must equal the amounts of the first one or
+    /// more of them, in plan order, and those milestones become `Paid`.
+    /// Nothing can be claimed while a proposed plan awaits the school's
+    /// approval.
+    ///
+    /// # Arguments
+    /// * `env`           - The contract environment
+    /// * `student`       - The student address receiving funds (must authorize)
+    /// * `pool_id`       - The ID of the pool to claim from
+    /// * `claim_amount`  - The amount to claim this call (must be > 0)
+    /// * `token_address` - The token used for the transfer; must be the pool token
+    ///
+    /// # Panics
+    /// - `"Claim amount must be positive"` if `claim_amount <= 0`
+    /// - `ContractError::PoolIsClosed` if the pool is closed
+    /// - `ContractError::InvalidPoolState` unless the pool is `Active` or `Completed`
+    /// - `ContractError::TokenMismatch` if `token_address` is not the pool token
+    /// - `"Application status not found"` if no status has been set
+    /// - `"Application is not approved"` if status is not `Approved`
+    /// - `"Application not found"` if the approval stored no `Application`
+    /// - `ContractError::ExceedsApprovedAmount` if
+    ///   `amount_claimed + claim_amount > approved_amount`
+    /// - `ContractError::ExceedsVerifiedMilestones` if `claim_amount` is more
+    ///   than the verified but unpaid milestones, or a plan awaits approval
+    /// - `ContractError::PartialMilestoneClaim` if `claim_amount` ends part
+    ///   way through a verified milestone
+    /// - `DisbursementError::ExceedsVestedAmount` if the claimed total would
+    ///   pass the vested amount
+    /// - `"Overdraw attempt"` if `amount_claimed + claim_amount > collected`
+    pub fn claim_funds(
+        env: Env,
+        student: Address,
+        pool_id: u32,
+        claim_amount: i128,
+        token_address: Address,
+    ) {
+        student.require_auth();
+
+        if claim_amount <= 0 {
+            panic!("Claim amount must be positive");
+        }
+
+        // Verify application is approved
+        let status = Self::read_application_status(&env, pool_id, &student)
+            .unwrap_or_else(|| panic!("Application status not found"));
+
+        if status != ApplicationStatus::Approved {
+            panic!("Application is not approved");
+        }
+
+        // Load pool to check available collected funds
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_open_for_awards(&env, &pool);
+        Self::ensure_pool_token(&env, &pool, &token_address);
+
+        // Load the Application record stored at approval
+        let app_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let mut application: Application = env
+            .storage()
+            .persistent()
+            .get::<_, Application>(&app_key)
+            .unwrap_or_else(|| panic!("Application not found"));
+
+        // Enforce the partial-payment invariant
+        if application.amount_claimed + claim_amount > application.approved_amount {
+            env.panic_with_error(ContractError::ExceedsApprovedAmount);
+        }
+        Self::pay_verified_milestones(&env, pool_id, &student, claim_amount as u128);
+        Self::disburse_claim(
+            &env,
+            pool_id,
+            &student,
+            &pool,
+            &mut application,
+            claim_amount,
+        );
+    }
+
+    /// Attach a linear vesting schedule to an approved student's award.
+    ///
+    /// Only the pool sponsor may call this, and only before the student has
+    /// claimed anything. Once set, `claim_funds` releases no more than the
+    /// vested amount. Emits `VESTING_SET` with `(student, schedule)`.
+    ///
+    /// # Panics
+    /// - `DisbursementError::ApplicationNotApproved` if the student is not `Approved`
+    /// - `ContractError::ClaimAlreadyMade` if the student has already claimed funds
+    /// - `DisbursementError::InvalidVestingSchedule` if the timestamps are out of order
+    pub fn set_vesting_schedule(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+        schedule: VestingSchedule,
+    ) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        if application.amount_claimed > 0 {
+            env.panic_with_error(ContractError::ClaimAlreadyMade);
+        }
+        if schedule.start >= schedule.end
+            || schedule.cliff < schedule.start
+            || schedule.cliff > schedule.end
+        {
+            env.panic_with_error(DisbursementError::InvalidVestingSchedule);
+        }
+
+        let vesting_key = (Symbol::new(&env, VESTING_PREFIX), pool_id, student.clone());
+        env.storage().persistent().set(&vesting_key, &schedule);
+
+        env.events()
+            .publish((VESTING_SET, pool_id), (student, schedule));
+    }
+
+    /// Choose who receives a student's claims.
+    ///
+    /// Only the pool sponsor may call this, and only before the student has
+    /// claimed anything. With `Payee::School`, claims and milestone payouts go
+    /// to the linked school's registered payout address. Emits `PAYEE_SET`
+    /// with `(student, payee)`.
+    ///
+    /// # Panics
+    /// - `DisbursementError::ApplicationNotApproved` if the student is not `Approved`
+    /// - `ContractError::ClaimAlreadyMade` if the student has already claimed funds
+    /// - `ContractError::PoolSchoolNotSet` if `Payee::School` is chosen for a
+    ///   pool without a linked school
+    pub fn set_application_payee(env: Env, pool_id: u32, student: Address, payee: Payee) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+
+        let application = Self::approved_application(&env, pool_id, &student);
+        if application.amount_claimed > 0 {
+            env.panic_with_error(ContractError::ClaimAlreadyMade);
+        }
+        let pool_school_key = (Symbol::new(&env, POOL_SCHOOL_PREFIX), pool_id);
+        if payee == Payee::School && !env.storage().persistent().has(&pool_school_key) {
+            env.panic_with_error(ContractError::PoolSchoolNotSet);
+        }
+
+        let payee_key = (Symbol::new(&env, PAYEE_PREFIX), pool_id, student.clone());
+        env.storage().persistent().set(&payee_key, &payee);
+
+        env.events().publish((PAYEE_SET, pool_id), (student, payee));
+    }
+
+    /// Get who receives a student's claims; `Payee::Student` unless changed.
+    pub fn get_application_payee(env: Env, pool_id: u32, student: Address) -> Payee {
+        let payee_key = (Symbol::new(&env, PAYEE_PREFIX), pool_id, student);
+        env.storage()
+            .persistent()
+            .get::<_, Payee>(&payee_key)
+            .unwrap_or(Payee::Student)
+    }
+
+    /// Get the vesting schedule attached to a student's award, if any.
+    pub fn get_vesting_schedule(
+        env: Env,
+        pool_id: u32,
+        student: Address,
+    ) -> Option<VestingSchedule> {
+        let vesting_key = (Symbol::new(&env, VESTING_PREFIX), pool_id, student);
+        env.storage()
+            .persistent()
+            .get::<_, VestingSchedule>(&vesting_key)
+    }
+
+    /// Amount the student could claim right now.
+    ///
+    /// This is the approved amount less what has been claimed, limited to
+    /// the vested amount under a vesting schedule and, under a milestone
+    /// plan, to the whole verified milestones that fit, taken in plan order.
+    /// Returns 0 unless the student is `Approved`, or while a proposed plan
+    /// awaits approval.
+    pub fn get_claimable(env: Env, pool_id: u32, student: Address) -> i128 {
+        if Self::read_application_status(&env, pool_id, &student)
+            != Some(ApplicationStatus::Approved)
+        {
+            return 0;
+        }
+        let app_key = (
+            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let Some(application) = env.storage().persistent().get::<_, Application>(&app_key) else {
+            return 0;
+        };
+
+        let mut claimable =
+            Self::vested_amount(&env, pool_id, &student, &application) - application.amount_claimed;
+        let milestones_key = (
+            Symbol::new(&env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let proposed_key = (
+            Symbol::new(&env, PROPOSED_MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        if env.storage().persistent().has(&milestones_key)
+            || env.storage().persistent().has(&proposed_key)
+        {
+            let payable = Self::payable_verified_amount(&env, pool_id, &student, &application);
+            claimable = claimable.min(payable);
+        }
+        claimable.max(0)
+    }
+
+    /// Claim accumulated protocol fees on behalf of the protocol/treasury.
+    ///
+    /// Allows Protocol Admins to retrieve all accumulated fees from operations.
+    /// This function separates fee tracking cleanly from active token allocations.
+    /// Fees are tracked per token, so only fees collected in `token_address`
+    /// are claimed.
+    ///
+    /// # Arguments
+    /// * `env`           - The contract environment
+    /// * `admin`         - The admin address claiming fees (must authorize)
+    /// * `token_address` - The token whose accumulated fees are claimed
+    ///
+    /// # Panics
+    /// - `ContractError::UnauthorizedAdmin` if the caller is not the stored admin address
+    /// - `ContractError::NoUnclaimedFees` if there are no accumulated fees to claim
+    pub fn claim_protocol_fees(env: Env, admin: Address, token_address: Address) -> i128 {
+        admin.require_auth();
+
+        // Verify caller is the protocol admin
+        let admin_key = Symbol::new(&env, ADMIN_KEY);
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&admin_key)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
+        if stored_admin != admin {
+            env.panic_with_error(ContractError::UnauthorizedAdmin);
+        }
+
+        // Get accumulated unclaimed fees
+        let unclaimed_fees_key = (Symbol::new(&env, UNCLAIMED_FEES), token_address.clone());
+        let fees: i128 = env
+            .storage()
+            .persistent()
+            .get::<_, i128>(&unclaimed_fees_key)
+            .unwrap_or(0);
+
+        if fees == 0 {
+            env.panic_with_error(ContractError::NoUnclaimedFees);
+        }
+
+        // Transfer accumulated fees to admin
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &admin, &fees);
+
+        // Reset unclaimed fees to 0
+        env.storage().persistent().set(&unclaimed_fees_key, &0i128);
+
+        // Issue #954: emit fees-claimed event
+        env.events().publish((FEES_CLAIMED, admin.clone()), (fees,));
+
+        fees
+    }
+
+    // ─── Creation Fee ─────────────────────────────────────────────────────────
+
+    /// Set the pool creation fee (in stroops / smallest token unit).
+    ///
+    /// Only the stored admin may call this function.
+    /// A fee of zero is valid (disables the creation fee).
+    /// A negative fee panics with `ContractError::InvalidFee`.
+    ///
+    /// Emits a `FEE_UPDATED` event on success.
+    ///
+    /// # Panics
+    /// - `ContractError::AdminNotSet` if no admin has been configured
+    /// - `ContractError::UnauthorizedAdmin` if `admin` does not match the stored admin
+    /// - `ContractError::InvalidFee` if `fee` is negative
+    pub fn set_creation_fee(env: Env, admin: Address, fee: i128) {
+        admin.require_auth();
+
+        let admin_key = Symbol::new(&env, ADMIN_KEY);
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&admin_key)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
+        if stored_admin != admin {
+            env.panic_with_error(ContractError::UnauthorizedAdmin);
+        }
+
+        if fee < 0 {
+            env.panic_with_error(ContractError::InvalidFee);
+        }
+
+        let fee_key = Symbol::new(&env, CREATION_FEE_KEY);
+        env.storage().persistent().set(&fee_key, &fee);
+
+        // Issue #954: use shared FEE_UPDATED constant instead of inline Symbol::new
+        env.events().publish((FEE_UPDATED,), fee);
+    }
+
+    /// Get the current pool creation fee.
+    /// Returns `0` if no fee has been set.
+    pub fn get_creation_fee(env: Env) -> i128 {
+        let fee_key = Symbol::new(&env, CREATION_FEE_KEY);
+        env.storage()
+            .persistent()
+            .get::<_, i128>(&fee_key)
+            .unwrap_or(0)
+    }
+
+    // ─── Refund Deadline ──────────────────────────────────────────────────────
+
+    /// Set the refund deadline (as a ledger sequence number) for a pool.
+    ///
+    /// Only the pool sponsor may call this.
+    /// The deadline must be in the future (greater than the current ledger).
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `"Error(Auth, InvalidAction)"` if caller is not the pool sponsor
+    /// - `"Deadline must be in the future"` if deadline <= current ledger
+    pub fn set_pool_deadline(env: Env, pool_id: u32, deadline: u32) {
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.sponsor.require_auth();
+
+        if deadline <= env.ledger().sequence() {
+            panic!("Deadline must be in the future");
+        }
+
+        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
+        env.storage().persistent().set(&deadline_key, &deadline);
+
+        // Issue #954: emit deadline-set event
+        env.events()
+            .publish((DEADLINE_SET, pool_id), (pool.sponsor.clone(), deadline));
+    }
+
+    /// Get the refund deadline ledger for a pool.
+    /// Returns `0` if no deadline has been set.
+    pub fn get_pool_deadline(env: Env, pool_id: u32) -> u32 {
+        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&deadline_key)
+            .unwrap_or(0)
+    }
+
+    /// Refund a donor's contribution from an expired pool.
+    ///
+    /// A refund is only permitted when ALL of the following hold:
+    ///   1. The pool has a deadline set (non-zero).
+    ///   2. The current ledger is strictly after the deadline
+    ///      (`current_ledger > deadline`).
+    ///   3. The grace period has elapsed
+    ///      (`current_ledger >= deadline + REFUND_GRACE_PERIOD_LEDGERS`).
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::PoolNotExpired` if the deadline has not passed (or grace not elapsed)
+    /// - `ContractError::NoContributionToRefund` if the donor has no recorded contribution
+    /// - `ContractError::TokenMismatch` if `token_address` is not the pool token
+    /// - `ContractError::InsufficientUnreservedFunds` if the refund would take
+    ///   funds reserved for approved students
+    pub fn refund_donation(env: Env, pool_id: u32, donor: Address, token_address: Address) {
+        donor.require_auth();
+
+        let mut pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_pool_token(&env, &pool, &token_address);
+
+        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
+        let deadline: u32 = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&deadline_key)
+            .unwrap_or(0);
+
+        let current_ledger = env.ledger().sequence();
+
+        // Deadline must have passed AND grace period must have elapsed
+        if deadline == 0
+            || current_ledger <= deadline
+            || current_ledger < deadline + REFUND_GRACE_PERIOD_LEDGERS
+        {
+            env.panic_with_error(ContractError::PoolNotExpired);
+        }
+
+        let contrib_key = (pool_id, "contribution", &donor);
+        let contribution: u128 = env
+            .storage()
+            .persistent()
+            .get::<_, u128>(&contrib_key)
+            .unwrap_or(0);
+
+        if contribution == 0 {
+            env.panic_with_error(ContractError::NoContributionToRefund);
+        }
+
+        // Clear the contribution record before transferring (re-entrancy guard)
+        env.storage().persistent().set(&contrib_key, &0u128);
+
+        // Reduce pool collected amount
+        pool.collected = pool.collected.saturating_sub(contribution);
+        env.storage().persistent().set(&pool_id, &pool);
+
+        Self::debit_pool_balance(&env, pool_id, &token_address, contribution as i128);
+        Self::ensure_reservations_covered(&env, pool_id, &pool);
+
+        let token_client = token::Client::new(&env, &token_address);
         token_client.transfer(
             &env.current_contract_address(),
-            &pool.sponsor,
-            &(surplus as i128),
+            &donor,
+            &(contribution as i128),
         );
 
-        pool.collected -= surplus;
-        env.storage().persistent().set(&pool_id, &pool);
+        // Issue #954: emit donation-refund event
+        env.events()
+            .publish((DONATION_REFUND, pool_id), (donor.clone(), contribution));
     }
 
-    /// Claim funds: allows an approved student to receive a partial or full
-    /// disbursement from a pool.
-    ///
-    /// Uses `Application` to persist `amount_claimed` across calls, enabling
-    /// streamed / milestone-based withdrawals where the student draws down
-    /// their approved allocation incrementally.
-    ///
-    /// # Arguments
-    /// * `env`           - The contract environment
-    /// * `student`       - The student address receiving funds (must authorize)
-    /// * `pool_id`       - The ID of the pool to claim from
-    /// * `claim_amount`  - The amount to claim this call (must be > 0)
-    /// * `token_address` - The token used for the transfer
+    /// Donate to a pool using a specific token.
     ///
-    /// # Panics
-    /// - `"Claim amount must be positive"` if `claim_amount <= 0`
-    /// - `"Application status not found"` if no status has been set
-    /// - `"Application is not approved"` if status != "Approved"
-    /// - `"Overdraw attempt"` if `amount_claimed + claim_amount > collected`
-    pub fn claim_funds(
+    /// Only the amount accepted under the pool's [`OverfundingPolicy`] is
+    /// transferred from the donor; reaching the goal moves the pool to
+    /// `Completed`.
+    pub fn donate_with_token(
         env: Env,
-        student: Address,
         pool_id: u32,
-        claim_amount: i128,
+        donor: Address,
         token_address: Address,
+        amount: i128,
     ) {
-        student.require_auth();
+        donor.require_auth();
 
-        if claim_amount <= 0 {
-            panic!("Claim amount must be positive");
+        let mut pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        Self::ensure_accepting_donations(&env, &pool);
+        Self::ensure_pool_token(&env, &pool, &token_address);
+
+        if amount <= 0 {
+            panic!("InvalidAmount");
         }
 
-        // Verify application is approved
-        let status_key = (
-            Symbol::new(&env, APPLICATION_STATUS_PREFIX),
-            pool_id,
-            student.clone(),
+        let amount = Self::accepted_donation(&env, &pool, amount as u128) as i128;
+
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&donor, &env.current_contract_address(), &amount);
+        Self::credit_pool_balance(&env, pool_id, &token_address, amount);
+
+        let new_collected = pool
+            .collected
+            .checked_add(amount as u128)
+            .expect("Collected amount overflow");
+
+        pool.collected = new_collected;
+        Self::complete_if_goal_reached(&env, pool_id, &mut pool);
+        env.storage().persistent().set(&pool_id, &pool);
+
+        Self::record_donation(&env, pool_id, &donor, &token_address, amount as u128);
+        // New funds may let the head of the waitlist be approved
+        Self::promote_waitlisted(&env, pool_id);
+
+        // Emit contribution event with privacy flag (true = private donation)
+        env.events().publish(
+            (CONTRIBUTION, pool_id),
+            (donor.clone(), amount, new_collected, true), // true = private contribution
         );
-        let status: String = env
+    }
+
+    // TODO: Replace with real implementation from issue #XYZ
+    // Mock emergency withdrawal request function
+    pub fn request_emergency_withdraw(
+        env: Env,
+        admin: Address,
+        pool_id: u32,
+        token_address: Address,
+        amount: i128,
+    ) {
+        admin.require_auth();
+
+        let admin_key = Symbol::new(&env, ADMIN_KEY);
+        let stored_admin: Address = env
             .storage()
             .persistent()
-            .get::<_, String>(&status_key)
-            .unwrap_or_else(|| panic!("Application status not found"));
-
-        if status != String::from_str(&env, APPLICATION_STATUS_APPROVED) {
-            panic!("Application is not approved");
+            .get::<_, Address>(&admin_key)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
+        if stored_admin != admin {
+            panic!("Error(Auth, InvalidAction)");
         }
 
-        // Load pool to check available collected funds
         let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_pool_token(&env, &pool, &token_address);
 
-        let collected = pool.collected as i128;
+        let withdrawal_key = (Symbol::new(&env, EMERGENCY_WITHDRAWAL_PREFIX), pool_id);
+        if env.storage().persistent().has(&withdrawal_key) {
+            panic!("EmergencyWithdrawalAlreadyRequested");
+        }
 
-        // Load or initialise the Application record for this student
-        let app_key = (
-            Symbol::new(&env, CLAIMED_AMOUNT_PREFIX),
+        let request = EmergencyWithdrawalRequest {
             pool_id,
-            student.clone(),
+            token_address,
+            amount,
+            request_timestamp: env.ledger().timestamp(),
+            requested_by: admin,
+        };
+        env.storage().persistent().set(&withdrawal_key, &request);
+    }
+
+    // TODO: Replace with real implementation from issue #XYZ
+    // Mock emergency withdrawal execution function
+    pub fn execute_emergency_withdraw(env: Env, pool_id: u32) {
+        let withdrawal_key = (Symbol::new(&env, EMERGENCY_WITHDRAWAL_PREFIX), pool_id);
+        let request: EmergencyWithdrawalRequest = env
+            .storage()
+            .persistent()
+            .get::<_, EmergencyWithdrawalRequest>(&withdrawal_key)
+            .expect("Emergency withdrawal not requested");
+
+        let current_timestamp = env.ledger().timestamp();
+        let time_elapsed = current_timestamp.saturating_sub(request.request_timestamp);
+
+        if time_elapsed < GRACE_PERIOD_SECS {
+            panic!("Grace period not elapsed");
+        }
+
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        Self::ensure_pool_token(&env, &pool, &request.token_address);
+        Self::debit_pool_balance(&env, pool_id, &request.token_address, request.amount);
+        Self::ensure_reservations_covered(&env, pool_id, &pool);
+
+        let token_client = token::Client::new(&env, &request.token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &request.requested_by,
+            &request.amount,
         );
-        let mut application: Application = env
+
+        env.storage().persistent().remove(&withdrawal_key);
+    }
+
+    // ─── Pool State Transitions ───────────────────────────────────────────────
+
+    /// Temporarily halt an `Active` pool. Sponsor or admin only.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::InvalidTransition` if the pool is not `Active`
+    /// - `ContractError::UnauthorizedTransition` if `caller` is neither sponsor nor admin
+    pub fn pause_pool(env: Env, pool_id: u32, caller: Address) {
+        Self::apply_transition(&env, pool_id, PoolState::Paused, &caller);
+    }
+
+    /// Return a `Paused` pool to `Active`. Sponsor or admin only.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::InvalidTransition` if the pool is not `Paused`
+    /// - `ContractError::UnauthorizedTransition` if `caller` is neither sponsor nor admin
+    pub fn resume_pool(env: Env, pool_id: u32, caller: Address) {
+        Self::apply_transition(&env, pool_id, PoolState::Active, &caller);
+    }
+
+    /// Cancel a pool.
+    ///
+    /// `Active` and `Paused` pools may be cancelled by the sponsor or admin;
+    /// a `Completed` pool may only be cancelled by the admin.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::InvalidTransition` if the pool cannot be cancelled from its state
+    /// - `ContractError::UnauthorizedTransition` if `caller` is not permitted for this edge
+    pub fn cancel_pool(env: Env, pool_id: u32, caller: Address) {
+        Self::apply_transition(&env, pool_id, PoolState::Cancelled, &caller);
+    }
+
+    /// Mark an `Active` pool as `Completed`. Sponsor or admin only.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::InvalidTransition` if the pool is not `Active`
+    /// - `ContractError::UnauthorizedTransition` if `caller` is neither sponsor nor admin
+    pub fn mark_completed(env: Env, pool_id: u32, caller: Address) {
+        Self::apply_transition(&env, pool_id, PoolState::Completed, &caller);
+    }
+
+    /// Mark a `Completed` pool as `Disbursed`. Admin only.
+    ///
+    /// # Panics
+    /// - `ContractError::PoolNotFound` if pool_id is invalid
+    /// - `ContractError::InvalidTransition` if the pool is not `Completed`
+    /// - `ContractError::UnauthorizedTransition` if `caller` is not the admin
+    pub fn mark_disbursed(env: Env, pool_id: u32, caller: Address) {
+        Self::apply_transition(&env, pool_id, PoolState::Disbursed, &caller);
+    }
+
+    /// Get the current lifecycle state of a pool.
+    pub fn get_pool_state(env: Env, pool_id: u32) -> PoolState {
+        let pool: Pool = env
             .storage()
             .persistent()
-            .get::<_, Application>(&app_key)
-            .unwrap_or(Application {
-                approved_amount: collected,
-                amount_claimed: 0,
-            });
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        pool.state
+    }
+}
+
+impl Contract {
+    /// Load a pool, run it through [`Contract::transition_pool`] and persist it.
+    fn apply_transition(env: &Env, pool_id: u32, to: PoolState, caller: &Address) {
+        caller.require_auth();
+
+        let pool: Pool = env
+            .storage()
+            .persistent()
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+
+        let pool = Self::transition_pool(env, pool_id, pool, to, caller);
+        env.storage().persistent().set(&pool_id, &pool);
+    }
+
+    /// Validate `pool.state -> to` against the transition table and the
+    /// caller's role, then return the pool in its new state.
+    ///
+    /// Emits `POOL_STATE_SET` with `(old_state, new_state, caller)`. The
+    /// caller is expected to have already been authenticated.
+    fn transition_pool(
+        env: &Env,
+        pool_id: u32,
+        mut pool: Pool,
+        to: PoolState,
+        caller: &Address,
+    ) -> Pool {
+        let authority = transition_authority(&pool.state, &to)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::InvalidTransition));
+
+        let is_sponsor = *caller == pool.sponsor;
+        let is_admin = Self::read_admin(env).is_some_and(|admin| admin == *caller);
+        let permitted = match authority {
+            TransitionAuthority::Sponsor => is_sponsor,
+            TransitionAuthority::Admin => is_admin,
+            TransitionAuthority::SponsorOrAdmin => is_sponsor || is_admin,
+        };
+        if !permitted {
+            env.panic_with_error(ContractError::UnauthorizedTransition);
+        }
+
+        let old_state = pool.state.clone();
+        pool.state = to.clone();
+
+        env.events()
+            .publish((POOL_STATE_SET, pool_id), (old_state, to, caller.clone()));
+
+        pool
+    }
+
+    /// Reject donations to closed pools and to pools not open for funding.
+    ///
+    /// `Active` pools always accept donations; `Completed` pools only do so
+    /// under `OverfundingPolicy::AcceptAll`.
+    fn ensure_accepting_donations(env: &Env, pool: &Pool) {
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
 
-        // Enforce the partial-payment invariant
-        if application.amount_claimed + claim_amount > collected {
-            panic!("Overdraw attempt");
+        let accepting = match pool.state {
+            PoolState::Active => true,
+            PoolState::Completed => pool.overfunding_policy == OverfundingPolicy::AcceptAll,
+            _ => false,
+        };
+        if !accepting {
+            env.panic_with_error(ContractError::InvalidPoolState);
         }
+    }
 
-        // Accumulate protocol fees (1% of claim amount)
-        // Fee tracking is isolated from student allocations
-        let fee = claim_amount / 100;
-        let net_transfer = claim_amount - fee;
+    /// Reject applications, decisions and payouts to students unless the
+    /// pool is `Active` or `Completed`.
+    fn ensure_open_for_awards(env: &Env, pool: &Pool) {
+        if pool.is_closed {
+            env.panic_with_error(ContractError::PoolIsClosed);
+        }
+        if !matches!(pool.state, PoolState::Active | PoolState::Completed) {
+            env.panic_with_error(ContractError::InvalidPoolState);
+        }
+    }
 
-        // Disburse tokens to the student
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&env.current_contract_address(), &student, &net_transfer);
-        let unclaimed_fees_key = Symbol::new(&env, UNCLAIMED_FEES);
-        let mut current_fees: i128 = env
+    /// Record a received donation in the donor ledger.
+    ///
+    /// Counts the donor once per pool, appends a [`DonationEntry`] to the
+    /// pool's list and the donor's history, and adds `amount` to the donor's
+    /// refundable contribution.
+    fn record_donation(env: &Env, pool_id: u32, donor: &Address, token: &Address, amount: u128) {
+        // Track unique donors
+        let donor_key = (pool_id, "donor", donor);
+        if !env.storage().persistent().has(&donor_key) {
+            env.storage().persistent().set(&donor_key, &true);
+            let donor_count: u32 = env
+                .storage()
+                .persistent()
+                .get::<_, u32>(&(pool_id, "d_count"))
+                .unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&(pool_id, "d_count"), &(donor_count + 1));
+        }
+
+        // Append the entry to the pool's donation list
+        let count_key = (Symbol::new(env, DONATION_COUNT_PREFIX), pool_id);
+        let idx: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let entry = DonationEntry {
+            pool_id,
+            donor: donor.clone(),
+            token: token.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        let entry_key = (Symbol::new(env, DONATION_PREFIX), pool_id, idx);
+        env.storage().persistent().set(&entry_key, &entry);
+        env.storage().persistent().set(&count_key, &(idx + 1));
+
+        // Reference the entry from the donor's history
+        let history_count_key = (Symbol::new(env, DONOR_HISTORY_COUNT_PREFIX), donor.clone());
+        let history_len: u32 = env
             .storage()
             .persistent()
-            .get::<_, i128>(&unclaimed_fees_key)
+            .get(&history_count_key)
             .unwrap_or(0);
-        current_fees += fee;
+        let ref_key = (
+            Symbol::new(env, DONOR_HISTORY_PREFIX),
+            donor.clone(),
+            history_len,
+        );
+        env.storage().persistent().set(&ref_key, &(pool_id, idx));
         env.storage()
             .persistent()
-            .set(&unclaimed_fees_key, &current_fees);
+            .set(&history_count_key, &(history_len + 1));
 
-        // Persist the updated running total
-        application.amount_claimed += claim_amount;
-        env.storage().persistent().set(&app_key, &application);
+        // Track individual donor's total contribution
+        let contrib_key = (pool_id, "contribution", donor);
+        let current_contrib: u128 = env.storage().persistent().get(&contrib_key).unwrap_or(0);
+        env.storage().persistent().set(
+            &contrib_key,
+            &current_contrib
+                .checked_add(amount)
+                .expect("Contribution overflow"),
+        );
+    }
 
-        // Issue #954: emit funds-claimed event
-        env.events().publish(
-            (FUNDS_CLAIMED, pool_id),
-            (student.clone(), claim_amount, application.amount_claimed),
+    /// Record tokens received by the contract on behalf of `pool_id`.
+    fn credit_pool_balance(env: &Env, pool_id: u32, token_address: &Address, amount: i128) {
+        let balance_key = (
+            Symbol::new(env, POOL_BALANCE_PREFIX),
+            pool_id,
+            token_address.clone(),
         );
+        let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+        let new_balance = balance.checked_add(amount).expect("Pool balance overflow");
+        env.storage().persistent().set(&balance_key, &new_balance);
     }
 
-    /// Claim accumulated protocol fees on behalf of the protocol/treasury.
-    ///
-    /// Allows Protocol Admins to retrieve all accumulated fees from operations.
-    /// This function separates fee tracking cleanly from active token allocations.
-    ///
-    /// # Arguments
-    /// * `env`           - The contract environment
-    /// * `admin`         - The admin address claiming fees (must authorize)
-    /// * `token_address` - The token to transfer fees as
+    /// Record tokens leaving the contract on behalf of `pool_id`.
     ///
     /// # Panics
-    /// - `ContractError::UnauthorizedAdmin` if the caller is not the stored admin address
-    /// - `ContractError::NoUnclaimedFees` if there are no accumulated fees to claim
-    pub fn claim_protocol_fees(env: Env, admin: Address, token_address: Address) -> i128 {
-        admin.require_auth();
-
-        // Verify caller is the protocol admin
-        let admin_key = Symbol::new(&env, ADMIN_KEY);
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get::<_, Address>(&admin_key)
-            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
-        if stored_admin != admin {
-            env.panic_with_error(ContractError::UnauthorizedAdmin);
+    /// - `ContractError::InsufficientPoolBalance` if the pool holds less than `amount`
+    fn debit_pool_balance(env: &Env, pool_id: u32, token_address: &Address, amount: i128) {
+        let balance_key = (
+            Symbol::new(env, POOL_BALANCE_PREFIX),
+            pool_id,
+            token_address.clone(),
+        );
+        let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+        if amount < 0 || amount > balance {
+            env.panic_with_error(ContractError::InsufficientPoolBalance);
         }
-
-        // Get accumulated unclaimed fees
-        let unclaimed_fees_key = Symbol::new(&env, UNCLAIMED_FEES);
-        let fees: i128 = env
-            .storage()
+        env.storage()
             .persistent()
-            .get::<_, i128>(&unclaimed_fees_key)
-            .unwrap_or(0);
+            .set(&balance_key, &(balance - amount));
+    }
 
-        if fees == 0 {
-            env.panic_with_error(ContractError::NoUnclaimedFees);
+    /// Reject any token other than the one the pool was created with.
+    fn ensure_pool_token(env: &Env, pool: &Pool, token_address: &Address) {
+        if pool.token != *token_address {
+            env.panic_with_error(ContractError::TokenMismatch);
         }
+    }
 
-        // Transfer accumulated fees to admin
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&env.current_contract_address(), &admin, &fees);
+    /// Apply the pool's overfunding policy and return how much of `amount`
+    /// the pool accepts.
+    ///
+    /// # Panics
+    /// - `ContractError::GoalExceeded` if a `RejectExcess` donation overshoots
+    ///   the goal, or a `CapAndRefund` pool has nothing left to raise
+    fn accepted_donation(env: &Env, pool: &Pool, amount: u128) -> u128 {
+        let remaining = pool.goal.saturating_sub(pool.collected);
+        match pool.overfunding_policy {
+            OverfundingPolicy::AcceptAll => amount,
+            OverfundingPolicy::RejectExcess => {
+                if amount > remaining {
+                    env.panic_with_error(ContractError::GoalExceeded);
+                }
+                amount
+            }
+            OverfundingPolicy::CapAndRefund => {
+                if remaining == 0 {
+                    env.panic_with_error(ContractError::GoalExceeded);
+                }
+                amount.min(remaining)
+            }
+        }
+    }
 
-        // Reset unclaimed fees to 0
-        env.storage().persistent().set(&unclaimed_fees_key, &0i128);
+    /// Move an `Active` pool to `Completed` once `collected` reaches `goal`.
+    ///
+    /// Emits `POOL_STATE_SET` (with the contract as the caller) followed by
+    /// `GOAL_REACHED` with `(collected, goal)`.
+    fn complete_if_goal_reached(env: &Env, pool_id: u32, pool: &mut Pool) {
+        if pool.state != PoolState::Active || pool.collected < pool.goal {
+            return;
+        }
 
-        // Issue #954: emit fees-claimed event
+        pool.state = PoolState::Completed;
+        env.events().publish(
+            (POOL_STATE_SET, pool_id),
+            (
+                PoolState::Active,
+                PoolState::Completed,
+                env.current_contract_address(),
+            ),
+        );
         env.events()
-            .publish((FEES_CLAIMED, admin.clone()), (fees,));
+            .publish((GOAL_REACHED, pool_id), (pool.collected, pool.goal));
+    }
 
-        fees
+    /// Append a sponsor change of `parameter` to the pool's parameter history.
+    fn record_parameter_change(
+        env: &Env,
+        pool_id: u32,
+        parameter: PoolParameter,
+        old_value: u128,
+        new_value: u128,
+    ) {
+        let count_key = (Symbol::new(env, PARAM_HISTORY_COUNT_PREFIX), pool_id);
+        let idx: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let change = ParameterChange {
+            parameter,
+            old_value,
+            new_value,
+            timestamp: env.ledger().timestamp(),
+        };
+        let entry_key = (Symbol::new(env, PARAM_HISTORY_PREFIX), pool_id, idx);
+        env.storage().persistent().set(&entry_key, &change);
+        env.storage().persistent().set(&count_key, &(idx + 1));
     }
 
-    // ─── Creation Fee ─────────────────────────────────────────────────────────
+    /// Check every `PoolMetadata` field against its length or count limit.
+    fn validate_metadata(env: &Env, metadata: &PoolMetadata) {
+        if metadata.description.len() > MAX_DESCRIPTION_LENGTH as u32 {
+            panic!("Description exceeds maximum length");
+        }
+        if metadata.website_url.len() > MAX_URL_LENGTH as u32 {
+            env.panic_with_error(ContractError::UrlTooLong);
+        }
+        if metadata.social_urls.len() > MAX_SOCIAL_URLS {
+            env.panic_with_error(ContractError::TooManySocialUrls);
+        }
+        for url in metadata.social_urls.iter() {
+            if url.len() > MAX_URL_LENGTH as u32 {
+                env.panic_with_error(ContractError::UrlTooLong);
+            }
+        }
+        if metadata.cover_image_hash.len() > MAX_IMAGE_HASH_LENGTH as u32 {
+            env.panic_with_error(ContractError::ImageHashTooLong);
+        }
+        if metadata.tags.len() > MAX_TAGS {
+            env.panic_with_error(ContractError::TooManyTags);
+        }
+        for tag in metadata.tags.iter() {
+            if tag.is_empty() || tag.len() > MAX_TAG_LENGTH as u32 {
+                env.panic_with_error(ContractError::InvalidTag);
+            }
+        }
+    }
 
-    /// Set the pool creation fee (in stroops / smallest token unit).
-    ///
-    /// Only the stored admin may call this function.
-    /// A fee of zero is valid (disables the creation fee).
-    /// A negative fee panics with `ContractError::InvalidFee`.
-    ///
-    /// Emits a `FEE_UPDATED` event on success.
+    /// Move a student's application to `to` if the status transition table
+    /// allows it. Callers are expected to have authorized the change.
     ///
-    /// # Panics
-    /// - `ContractError::AdminNotSet` if no admin has been configured
-    /// - `ContractError::UnauthorizedAdmin` if `admin` does not match the stored admin
-    /// - `ContractError::InvalidFee` if `fee` is negative
-    pub fn set_creation_fee(env: Env, admin: Address, fee: i128) {
-        admin.require_auth();
+    /// Leaving `Approved` releases whatever the student has not yet claimed
+    /// and promotes the next waitlisted student; leaving `Waitlisted` removes
+    /// the student from the waitlist.
+    fn transition_application(env: &Env, pool_id: u32, student: &Address, to: ApplicationStatus) {
+        let from = Self::read_application_status(env, pool_id, student)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::StudentHasNotApplied));
+        if !application_transition_allowed(&from, &to) {
+            env.panic_with_error(ContractError::InvalidStatusTransition);
+        }
+        if from == ApplicationStatus::Approved {
+            let app_key = (
+                Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+                pool_id,
+                student.clone(),
+            );
+            if let Some(application) = env.storage().persistent().get::<_, Application>(&app_key) {
+                let unclaimed = application.approved_amount - application.amount_claimed;
+                Self::release_reservation(env, pool_id, unclaimed.max(0) as u128);
+            }
+        }
+        if from == ApplicationStatus::Waitlisted {
+            Self::remove_from_waitlist(env, pool_id, student);
+        }
+        Self::write_application_status(env, pool_id, student, to);
+        if from == ApplicationStatus::Approved {
+            Self::promote_waitlisted(env, pool_id);
+        }
+    }
 
-        let admin_key = Symbol::new(&env, ADMIN_KEY);
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get::<_, Address>(&admin_key)
-            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
-        if stored_admin != admin {
-            env.panic_with_error(ContractError::UnauthorizedAdmin);
+    /// Whether the pool already has `max_recipients` approved students.
+    fn at_capacity(pool: &Pool) -> bool {
+        pool.max_recipients
+            .is_some_and(|max| pool.recipient_count >= max)
+    }
+
+    /// Status a new approval takes: `Waitlisted` once the pool has
+    /// `max_recipients` approved students or while anyone is still queued,
+    /// so approvals never overtake the waitlist; otherwise `Approved`.
+    fn admission_status(env: &Env, pool_id: u32, pool: &Pool) -> ApplicationStatus {
+        if Self::at_capacity(pool) || !Self::get_waitlist(env.clone(), pool_id).is_empty() {
+            ApplicationStatus::Waitlisted
+        } else {
+            ApplicationStatus::Approved
         }
+    }
 
-        if fee < 0 {
-            env.panic_with_error(ContractError::InvalidFee);
+    /// Record an approval that has just moved the student to `status`:
+    /// reserve it if `Approved`, or store it and queue the student if
+    /// `Waitlisted`.
+    fn admit_application(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        status: ApplicationStatus,
+        approved_amount: i128,
+    ) {
+        if status == ApplicationStatus::Approved {
+            Self::reserve_approval(env, pool_id, student, approved_amount);
+            return;
         }
 
-        let fee_key = Symbol::new(&env, CREATION_FEE_KEY);
-        env.storage().persistent().set(&fee_key, &fee);
+        let app_key = (
+            Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let application = Application {
+            approved_amount,
+            amount_claimed: 0,
+        };
+        env.storage().persistent().set(&app_key, &application);
 
-        // Issue #954: use shared FEE_UPDATED constant instead of inline Symbol::new
-        env.events().publish((FEE_UPDATED,), fee);
+        let index = Self::application_index(env, pool_id, student);
+        let mut waitlist = Self::get_waitlist(env.clone(), pool_id);
+        let position = waitlist
+            .iter()
+            .position(|queued| Self::application_index(env, pool_id, &queued) > index)
+            .unwrap_or(waitlist.len() as usize);
+        waitlist.insert(position as u32, student.clone());
+        let waitlist_key = (Symbol::new(env, WAITLIST_PREFIX), pool_id);
+        env.storage().persistent().set(&waitlist_key, &waitlist);
+        Self::promote_waitlisted(env, pool_id);
     }
 
-    /// Get the current pool creation fee.
-    /// Returns `0` if no fee has been set.
-    pub fn get_creation_fee(env: Env) -> i128 {
-        let fee_key = Symbol::new(&env, CREATION_FEE_KEY);
-        env.storage()
-            .persistent()
-            .get::<_, i128>(&fee_key)
-            .unwrap_or(0)
+    /// Approve waitlisted students in order while the pool is under its cap
+    /// and can reserve the head student's approved amount. Emits
+    /// `WAITLIST_PROMOTED` with `(student, approved_amount)` for each.
+    fn promote_waitlisted(env: &Env, pool_id: u32) {
+        while Self::promote_next_waitlisted(env, pool_id) {}
     }
 
-    // ─── Refund Deadline ──────────────────────────────────────────────────────
-
-    /// Set the refund deadline (as a ledger sequence number) for a pool.
-    ///
-    /// Only the pool sponsor may call this.
-    /// The deadline must be in the future (greater than the current ledger).
-    ///
-    /// # Panics
-    /// - `ContractError::PoolNotFound` if pool_id is invalid
-    /// - `"Error(Auth, InvalidAction)"` if caller is not the pool sponsor
-    /// - `"Deadline must be in the future"` if deadline <= current ledger
-    pub fn set_pool_deadline(env: Env, pool_id: u32, deadline: u32) {
+    /// Approve the first waitlisted student if possible; returns whether
+    /// anyone was promoted.
+    fn promote_next_waitlisted(env: &Env, pool_id: u32) -> bool {
         let pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        if Self::at_capacity(&pool) {
+            return false;
+        }
+        let Some(next) = Self::get_waitlist(env.clone(), pool_id).first() else {
+            return false;
+        };
 
-        pool.sponsor.require_auth();
-
-        if deadline <= env.ledger().sequence() {
-            panic!("Deadline must be in the future");
+        let app_key = (
+            Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            next.clone(),
+        );
+        let approved_amount = env
+            .storage()
+            .persistent()
+            .get::<_, Application>(&app_key)
+            .map(|application| application.approved_amount)
+            .unwrap_or(0);
+        // The queue waits until the head student's amount can be reserved
+        if approved_amount as u128 > Self::unreserved_balance(env, pool_id, &pool) {
+            return false;
         }
 
-        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
-        env.storage().persistent().set(&deadline_key, &deadline);
+        Self::remove_from_waitlist(env, pool_id, &next);
+        Self::write_application_status(env, pool_id, &next, ApplicationStatus::Approved);
+        Self::reserve_approval(env, pool_id, &next, approved_amount);
 
-        // Issue #954: emit deadline-set event
         env.events()
-            .publish((DEADLINE_SET, pool_id), (pool.sponsor.clone(), deadline));
+            .publish((WAITLIST_PROMOTED, pool_id), (next, approved_amount));
+        true
     }
 
-    /// Get the refund deadline ledger for a pool.
-    /// Returns `0` if no deadline has been set.
-    pub fn get_pool_deadline(env: Env, pool_id: u32) -> u32 {
-        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
-        env.storage()
-            .persistent()
-            .get::<_, u32>(&deadline_key)
-            .unwrap_or(0)
+    fn remove_from_waitlist(env: &Env, pool_id: u32, student: &Address) {
+        let mut waitlist = Self::get_waitlist(env.clone(), pool_id);
+        if let Some(position) = waitlist.first_index_of(student) {
+            waitlist.remove(position);
+            let waitlist_key = (Symbol::new(env, WAITLIST_PREFIX), pool_id);
+            env.storage().persistent().set(&waitlist_key, &waitlist);
+        }
     }
 
-    /// Refund a donor's contribution from an expired pool.
-    ///
-    /// A refund is only permitted when ALL of the following hold:
-    ///   1. The pool has a deadline set (non-zero).
-    ///   2. The current ledger is strictly after the deadline
-    ///      (`current_ledger > deadline`).
-    ///   3. The grace period has elapsed
-    ///      (`current_ledger >= deadline + REFUND_GRACE_PERIOD_LEDGERS`).
-    ///
-    /// # Panics
-    /// - `ContractError::PoolNotFound` if pool_id is invalid
-    /// - `ContractError::PoolNotExpired` if the deadline has not passed (or grace not elapsed)
-    /// - `ContractError::NoContributionToRefund` if the donor has no recorded contribution
-    pub fn refund_donation(env: Env, pool_id: u32, donor: Address, token_address: Address) {
-        donor.require_auth();
-
+    /// Store a new approval's `Application` and reserve its amount from the
+    /// pool's unreserved balance.
+    fn reserve_approval(env: &Env, pool_id: u32, student: &Address, approved_amount: i128) {
         let mut pool: Pool = env
             .storage()
             .persistent()
             .get::<_, Pool>(&pool_id)
             .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        if approved_amount as u128 > Self::unreserved_balance(env, pool_id, &pool) {
+            env.panic_with_error(ContractError::InsufficientUnreservedFunds);
+        }
+
+        let app_key = (
+            Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let application = Application {
+            approved_amount,
+            amount_claimed: 0,
+        };
+        env.storage().persistent().set(&app_key, &application);
+
+        pool.reserved += approved_amount as u128;
+        env.storage().persistent().set(&pool_id, &pool);
+    }
+
+    /// Load the `Application` of a student whose status is `Approved`.
+    fn approved_application(env: &Env, pool_id: u32, student: &Address) -> Application {
+        if Self::read_application_status(env, pool_id, student) != Some(ApplicationStatus::Approved)
+        {
+            env.panic_with_error(DisbursementError::ApplicationNotApproved);
+        }
+        let app_key = (
+            Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage()
+            .persistent()
+            .get::<_, Application>(&app_key)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::ApplicationNotApproved))
+    }
+
+    /// Check that every milestone in a new plan is `Pending`, has a positive
+    /// amount and is due in the future, and return their total.
+    fn validate_new_milestones(env: &Env, milestones: &Vec<Milestone>) -> u128 {
+        if milestones.is_empty() {
+            panic!("Milestones required");
+        }
 
-        let deadline_key = (Symbol::new(&env, POOL_DEADLINE_PREFIX), pool_id);
-        let deadline: u32 = env
+        let now = env.ledger().timestamp();
+        let mut sum: u128 = 0;
+        for milestone in milestones.iter() {
+            if milestone.status != MilestoneStatus::Pending
+                || milestone.amount == 0
+                || milestone.due_at <= now
+            {
+                env.panic_with_error(ContractError::InvalidMilestonePlan);
+            }
+            sum = sum
+                .checked_add(milestone.amount)
+                .expect("Milestone amount overflow");
+        }
+        sum
+    }
+
+    /// Build the plan an amendment would produce: the active plan's `Paid`
+    /// milestones followed by `milestones`. Like any plan, it must total the
+    /// approved amount.
+    fn amended_plan(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        application: &Application,
+        milestones: &Vec<Milestone>,
+    ) -> Vec<Milestone> {
+        let milestones_key = (
+            Symbol::new(env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let active: Vec<Milestone> = env
             .storage()
             .persistent()
-            .get::<_, u32>(&deadline_key)
-            .unwrap_or(0);
+            .get(&milestones_key)
+            .unwrap_or_else(|| env.panic_with_error(DisbursementError::MilestonePlanNotFound));
 
-        let current_ledger = env.ledger().sequence();
+        let mut plan = Vec::new(env);
+        for milestone in active.iter() {
+            if milestone.status == MilestoneStatus::Paid {
+                plan.push_back(milestone);
+            }
+        }
+        plan.append(milestones);
 
-        // Deadline must have passed AND grace period must have elapsed
-        if deadline == 0
-            || current_ledger <= deadline
-            || current_ledger < deadline + REFUND_GRACE_PERIOD_LEDGERS
-        {
-            env.panic_with_error(ContractError::PoolNotExpired);
+        let total: u128 = plan.iter().map(|milestone| milestone.amount).sum();
+        if total != application.approved_amount as u128 {
+            env.panic_with_error(DisbursementError::MilestoneTotalMismatch);
         }
+        plan
+    }
 
-        let contrib_key = (pool_id, "contribution", &donor);
-        let contribution: u128 = env
+    /// Make `milestones` the student's active plan, recording it under the
+    /// next version number, and return that version.
+    fn activate_milestone_plan(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        milestones: &Vec<Milestone>,
+    ) -> u32 {
+        let milestones_key = (
+            Symbol::new(env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&milestones_key, milestones);
+
+        let version = Self::get_milestone_plan_version(env.clone(), pool_id, student.clone()) + 1;
+        let version_key = (
+            Symbol::new(env, PLAN_VERSION_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&version_key, &version);
+        let history_key = (
+            Symbol::new(env, PLAN_HISTORY_PREFIX),
+            pool_id,
+            student.clone(),
+            version,
+        );
+        env.storage().persistent().set(&history_key, milestones);
+        version
+    }
+
+    /// Drop a student's active plan with its evidence, any proposed plan and
+    /// any pending amendment. Earlier plan versions stay queryable, and a
+    /// later plan continues their numbering.
+    fn clear_milestone_state(env: &Env, pool_id: u32, student: &Address) {
+        let milestones_key = (
+            Symbol::new(env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let active: Vec<Milestone> = env
             .storage()
             .persistent()
-            .get::<_, u128>(&contrib_key)
-            .unwrap_or(0);
+            .get(&milestones_key)
+            .unwrap_or(Vec::new(env));
+        for index in 0..active.len() {
+            let evidence_key = (
+                Symbol::new(env, MILESTONE_EVIDENCE_PREFIX),
+                pool_id,
+                student.clone(),
+                index,
+            );
+            env.storage().persistent().remove(&evidence_key);
+        }
 
-        if contribution == 0 {
-            env.panic_with_error(ContractError::NoContributionToRefund);
+        for prefix in [
+            MILESTONES_PREFIX,
+            PROPOSED_MILESTONES_PREFIX,
+            AMENDMENT_PREFIX,
+        ] {
+            let key = (Symbol::new(env, prefix), pool_id, student.clone());
+            env.storage().persistent().remove(&key);
         }
+    }
 
-        // Clear the contribution record before transferring (re-entrancy guard)
-        env.storage().persistent().set(&contrib_key, &0u128);
+    /// Refuse to replace a student's milestone plan once any of it is paid.
+    fn ensure_milestone_plan_open(env: &Env, pool_id: u32, student: &Address) {
+        let milestones_key = (
+            Symbol::new(env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        let paid = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Milestone>>(&milestones_key)
+            .is_some_and(|milestones| {
+                milestones
+                    .iter()
+                    .any(|milestone| milestone.status == MilestoneStatus::Paid)
+            });
+        if paid {
+            env.panic_with_error(DisbursementError::MilestonePlanFrozen);
+        }
+    }
 
-        // Reduce pool collected amount
-        pool.collected = pool.collected.saturating_sub(contribution);
-        env.storage().persistent().set(&pool_id, &pool);
+    /// Mark `Pending` and `Submitted` milestones whose due timestamp has
+    /// passed as `Expired`.
+    fn expire_overdue_milestones(env: &Env, milestones: &mut Vec<Milestone>) {
+        let now = env.ledger().timestamp();
+        for i in 0..milestones.len() {
+            let mut milestone = milestones.get(i).unwrap();
+            let open = matches!(
+                milestone.status,
+                MilestoneStatus::Pending | MilestoneStatus::Submitted
+            );
+            if open && now > milestone.due_at {
+                milestone.status = MilestoneStatus::Expired;
+                milestones.set(i, milestone);
+            }
+        }
+    }
 
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &donor,
-            &(contribution as i128),
+    /// Mark the `Verified` milestones covered by `amount` as `Paid`, in plan
+    /// order, emitting `MILESTONE_PAID` for each. A plan still awaiting the
+    /// school's approval has no verified milestones yet; students with no
+    /// plan at all are not restricted.
+    fn pay_verified_milestones(env: &Env, pool_id: u32, student: &Address, amount: u128) {
+        let milestones_key = (
+            Symbol::new(env, MILESTONES_PREFIX),
+            pool_id,
+            student.clone(),
         );
+        let Some(mut milestones) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Milestone>>(&milestones_key)
+        else {
+            let proposed_key = (
+                Symbol::new(env, PROPOSED_MILESTONES_PREFIX),
+                pool_id,
+                student.clone(),
+            );
+            if env.storage().persistent().has(&proposed_key) {
+                env.panic_with_error(ContractError::ExceedsVerifiedMilestones);
+            }
+            return;
+        };
+        Self::expire_overdue_milestones(env, &mut milestones);
+
+        let verified: u128 = milestones
+            .iter()
+            .filter(|milestone| milestone.status == MilestoneStatus::Verified)
+            .map(|milestone| milestone.amount)
+            .sum();
+        if amount > verified {
+            env.panic_with_error(ContractError::ExceedsVerifiedMilestones);
+        }
 
-        // Issue #954: emit donation-refund event
-        env.events()
-            .publish((DONATION_REFUND, pool_id), (donor.clone(), contribution));
+        let mut remaining = amount;
+        for i in 0..milestones.len() {
+            if remaining == 0 {
+                break;
+            }
+            let mut milestone = milestones.get(i).unwrap();
+            if milestone.status != MilestoneStatus::Verified {
+                continue;
+            }
+            if milestone.amount > remaining {
+                env.panic_with_error(ContractError::PartialMilestoneClaim);
+            }
+            remaining -= milestone.amount;
+            env.events().publish(
+                (MILESTONE_PAID, pool_id),
+                (student.clone(), i, milestone.amount as i128),
+            );
+            milestone.status = MilestoneStatus::Paid;
+            milestones.set(i, milestone);
+        }
+        env.storage().persistent().set(&milestones_key, &milestones);
     }
 
-    /// Donate to a pool using a specific token.
-    pub fn donate_with_token(
-        env: Env,
+    /// Total of the `Verified` milestones, taken whole and in plan order, that
+    /// fit within what the student has vested and not yet been paid.
+    fn payable_verified_amount(
+        env: &Env,
         pool_id: u32,
-        donor: Address,
-        token_address: Address,
-        amount: i128,
-    ) {
-        donor.require_auth();
+        student: &Address,
+        application: &Application,
+    ) -> i128 {
+        let limit =
+            Self::vested_amount(env, pool_id, student, application) - application.amount_claimed;
+        let mut payable: i128 = 0;
+        for milestone in Self::get_milestones(env.clone(), pool_id, student.clone()).iter() {
+            if milestone.status != MilestoneStatus::Verified {
+                continue;
+            }
+            if payable + milestone.amount as i128 > limit {
+                break;
+            }
+            payable += milestone.amount as i128;
+        }
+        payable
+    }
 
-        let pool: Pool = env
+    /// Portion of the approved amount vested at the current ledger timestamp.
+    /// Awards without a vesting schedule are fully vested.
+    fn vested_amount(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        application: &Application,
+    ) -> i128 {
+        let vesting_key = (Symbol::new(env, VESTING_PREFIX), pool_id, student.clone());
+        let Some(schedule) = env
             .storage()
             .persistent()
-            .get::<_, Pool>(&pool_id)
-            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+            .get::<_, VestingSchedule>(&vesting_key)
+        else {
+            return application.approved_amount;
+        };
 
-        if pool.is_closed {
-            env.panic_with_error(ContractError::PoolIsClosed);
+        let now = env.ledger().timestamp();
+        if now < schedule.cliff {
+            0
+        } else if now >= schedule.end {
+            application.approved_amount
+        } else {
+            let elapsed = (now - schedule.start) as i128;
+            let duration = (schedule.end - schedule.start) as i128;
+            application
+                .approved_amount
+                .checked_mul(elapsed)
+                .expect("Vesting amount overflow")
+                / duration
         }
+    }
 
-        // TODO: Replace with real implementation from issue #XYZ
-        // Pool state validation
-        if pool.state != PoolState::Active {
-            env.panic_with_error(ContractError::InvalidPoolState);
+    /// Transfer `claim_amount` of the pool token to an approved student's
+    /// payee, net of the 1% protocol fee, and record it against their
+    /// application. Emits `FUNDS_CLAIMED`.
+    ///
+    /// # Panics
+    /// - `DisbursementError::ExceedsVestedAmount` if the claimed total would
+    ///   pass the vested amount
+    /// - `DisbursementError::PayoutAddressNotSet` if claims go to a school
+    ///   that has not registered a payout address
+    fn disburse_claim(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        pool: &Pool,
+        application: &mut Application,
+        claim_amount: i128,
+    ) {
+        let vested = Self::vested_amount(env, pool_id, student, application);
+        if application.amount_claimed + claim_amount > vested {
+            env.panic_with_error(DisbursementError::ExceedsVestedAmount);
         }
-
-        if amount <= 0 {
-            panic!("InvalidAmount");
+        if application.amount_claimed + claim_amount > pool.collected as i128 {
+            panic!("Overdraw attempt");
         }
 
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&donor, &env.current_contract_address(), &amount);
-
-        let new_collected = pool
-            .collected
-            .checked_add(amount as u128)
-            .expect("Collected amount overflow");
+        // Accumulate protocol fees (1% of claim amount)
+        // Fee tracking is isolated from student allocations
+        let fee = claim_amount / 100;
+        let net_transfer = claim_amount - fee;
 
-        let updated_pool = Pool {
-            sponsor: pool.sponsor,
-            goal: pool.goal,
-            collected: new_collected,
-            is_closed: pool.is_closed,
-            state: pool.state,
-            application_deadline: pool.application_deadline,
+        // The full claim leaves the pool; the fee portion moves to the
+        // protocol fee accumulator below
+        Self::debit_pool_balance(env, pool_id, &pool.token, claim_amount);
+
+        // Disburse tokens to the student, or to the school for tuition
+        let recipient = match Self::get_application_payee(env.clone(), pool_id, student.clone()) {
+            Payee::Student => student.clone(),
+            Payee::School => {
+                let school = Self::get_pool_school(env.clone(), pool_id);
+                Self::get_school_payout_address(env.clone(), school)
+                    .unwrap_or_else(|| env.panic_with_error(DisbursementError::PayoutAddressNotSet))
+            }
         };
-        env.storage().persistent().set(&pool_id, &updated_pool);
-
-        let donor_index: u32 = env
+        let token_client = token::Client::new(env, &pool.token);
+        token_client.transfer(&env.current_contract_address(), &recipient, &net_transfer);
+        let unclaimed_fees_key = (Symbol::new(env, UNCLAIMED_FEES), pool.token.clone());
+        let mut current_fees: i128 = env
             .storage()
             .persistent()
-            .get::<_, u32>(&(pool_id, "d_count"))
+            .get::<_, i128>(&unclaimed_fees_key)
             .unwrap_or(0);
+        current_fees += fee;
         env.storage()
             .persistent()
-            .set(&(pool_id, "d_count"), &(donor_index + 1));
+            .set(&unclaimed_fees_key, &current_fees);
 
-        // Emit contribution event with privacy flag (true = private donation)
+        // Persist the updated running total; claimed funds are no longer reserved
+        application.amount_claimed += claim_amount;
+        let app_key = (
+            Symbol::new(env, CLAIMED_AMOUNT_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&app_key, application);
+        Self::release_reservation(env, pool_id, claim_amount as u128);
+
+        // Issue #954: emit funds-claimed event
         env.events().publish(
-            (CONTRIBUTION, pool_id),
-            (donor.clone(), amount, new_collected, true), // true = private contribution
+            (FUNDS_CLAIMED, pool_id),
+            (
+                student.clone(),
+                claim_amount,
+                application.amount_claimed,
+                recipient,
+            ),
         );
-        // Track unique donors
-        let donor_key = (pool_id, "donor", &donor);
-        if !env.storage().persistent().has(&donor_key) {
-            env.storage().persistent().set(&donor_key, &true);
-            let donor_count: u32 = env
-                .storage()
-                .persistent()
-                .get::<_, u32>(&(pool_id, "d_count"))
-                .unwrap_or(0);
-            env.storage()
-                .persistent()
-                .set(&(pool_id, "d_count"), &(donor_count + 1));
-        }
+    }
 
-        // Track individual donor's total contribution
-        let contrib_key = (pool_id, "contribution", &donor);
-        let current_contrib: u128 = env.storage().persistent().get(&contrib_key).unwrap_or(0);
-        env.storage()
-            .persistent()
-            .set(&contrib_key, &(current_contrib + (amount as u128)));
+    /// Pool balance not yet reserved for approved students.
+    fn unreserved_balance(env: &Env, pool_id: u32, pool: &Pool) -> u128 {
+        let balance = Self::get_pool_balance(env.clone(), pool_id, pool.token.clone());
+        (balance.max(0) as u128).saturating_sub(pool.reserved)
     }
 
-    // TODO: Replace with real implementation from issue #XYZ
-    // Mock emergency withdrawal request function
-    pub fn request_emergency_withdraw(
-        env: Env,
-        admin: Address,
-        pool_id: u32,
-        token_address: Address,
-        amount: i128,
-    ) {
-        admin.require_auth();
+    /// Refuse an outflow that left the pool balance below what is reserved
+    /// for approved students.
+    fn ensure_reservations_covered(env: &Env, pool_id: u32, pool: &Pool) {
+        let balance = Self::get_pool_balance(env.clone(), pool_id, pool.token.clone());
+        if pool.reserved > balance.max(0) as u128 {
+            env.panic_with_error(ContractError::InsufficientUnreservedFunds);
+        }
+    }
 
-        let admin_key = Symbol::new(&env, ADMIN_KEY);
-        let stored_admin: Address = env
+    fn release_reservation(env: &Env, pool_id: u32, amount: u128) {
+        let mut pool: Pool = env
             .storage()
             .persistent()
-            .get::<_, Address>(&admin_key)
-            .unwrap_or_else(|| env.panic_with_error(ContractError::AdminNotSet));
-        if stored_admin != admin {
-            panic!("Error(Auth, InvalidAction)");
-        }
-
-        let withdrawal_key = (Symbol::new(&env, EMERGENCY_WITHDRAWAL_PREFIX), pool_id);
-        if env.storage().persistent().has(&withdrawal_key) {
-            panic!("EmergencyWithdrawalAlreadyRequested");
-        }
-
-        let request = EmergencyWithdrawalRequest {
-            pool_id,
-            token_address,
-            amount,
-            request_timestamp: env.ledger().timestamp(),
-            requested_by: admin,
-        };
-        env.storage().persistent().set(&withdrawal_key, &request);
+            .get::<_, Pool>(&pool_id)
+            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+        pool.reserved = pool.reserved.saturating_sub(amount);
+        env.storage().persistent().set(&pool_id, &pool);
     }
 
-    // TODO: Replace with real implementation from issue #XYZ
-    // Mock emergency withdrawal execution function
-    pub fn execute_emergency_withdraw(env: Env, pool_id: u32) {
-        let withdrawal_key = (Symbol::new(&env, EMERGENCY_WITHDRAWAL_PREFIX), pool_id);
-        let request: EmergencyWithdrawalRequest = env
-            .storage()
+    /// Submission index of a student's current application, or `u32::MAX`
+    /// if the student has none.
+    fn application_index(env: &Env, pool_id: u32, student: &Address) -> u32 {
+        let applicant_key = (Symbol::new(env, APPLICANT_PREFIX), pool_id, student.clone());
+        env.storage()
             .persistent()
-            .get::<_, EmergencyWithdrawalRequest>(&withdrawal_key)
-            .expect("Emergency withdrawal not requested");
+            .get::<_, u32>(&applicant_key)
+            .unwrap_or(u32::MAX)
+    }
 
-        let current_timestamp = env.ledger().timestamp();
-        let time_elapsed = current_timestamp.saturating_sub(request.request_timestamp);
+    /// Store a student's application status and emit `APP_STATUS_CHANGED`
+    /// with `(student, old_status, new_status)`. Keeps the pool's
+    /// `recipient_count` in step with moves into and out of `Approved`.
+    fn write_application_status(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+        status: ApplicationStatus,
+    ) {
+        let old_status = Self::read_application_status(env, pool_id, student);
+        let status_key = (
+            Symbol::new(env, APPLICATION_STATUS_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().set(&status_key, &status);
 
-        if time_elapsed < GRACE_PERIOD_SECS {
-            panic!("Grace period not elapsed");
+        let was_approved = old_status == Some(ApplicationStatus::Approved);
+        let is_approved = status == ApplicationStatus::Approved;
+        if was_approved != is_approved {
+            let mut pool: Pool = env
+                .storage()
+                .persistent()
+                .get::<_, Pool>(&pool_id)
+                .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
+            pool.recipient_count = if is_approved {
+                pool.recipient_count + 1
+            } else {
+                pool.recipient_count.saturating_sub(1)
+            };
+            env.storage().persistent().set(&pool_id, &pool);
         }
 
-        let token_client = token::Client::new(&env, &request.token_address);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &request.requested_by,
-            &request.amount,
+        env.events().publish(
+            (APP_STATUS_CHANGED, pool_id),
+            (student.clone(), old_status, status),
         );
-
-        env.storage().persistent().remove(&withdrawal_key);
     }
 
-    // TODO: Replace with real implementation from issue #XYZ
-    // Mock function to set pool state for testing
-    pub fn set_pool_state(env: Env, pool_id: u32, state: PoolState) {
-        let mut pool: Pool = env
-            .storage()
-            .persistent()
-            .get::<_, Pool>(&pool_id)
-            .unwrap_or_else(|| env.panic_with_error(ContractError::PoolNotFound));
-
-        let old_state = pool.state.clone();
-        pool.state = state.clone();
-        env.storage().persistent().set(&pool_id, &pool);
+    /// Read a student's application status.
+    fn read_application_status(
+        env: &Env,
+        pool_id: u32,
+        student: &Address,
+    ) -> Option<ApplicationStatus> {
+        let status_key = (
+            Symbol::new(env, APPLICATION_STATUS_PREFIX),
+            pool_id,
+            student.clone(),
+        );
+        env.storage().persistent().get(&status_key)
+    }
 
-        // Issue #954: emit pool-state-set event
-        env.events()
-            .publish((POOL_STATE_SET, pool_id), (old_state, state));
+    /// Return the stored platform admin, if one has been configured.
+    fn read_admin(env: &Env) -> Option<Address> {
+        let admin_key = Symbol::new(env, ADMIN_KEY);
+        env.storage().persistent().get::<_, Address>(&admin_key)
     }
 }
 
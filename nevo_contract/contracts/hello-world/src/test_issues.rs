@@ -2,9 +2,9 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
+    testutils::{Address as _, Events, Ledger},
     token::StellarAssetClient,
-    Address, Env, String, Symbol,
+    Address, Env, IntoVal, String, Symbol,
 };
 
 fn create_token(env: &Env, amount: i128, recipient: &Address) -> Address {
@@ -15,6 +15,28 @@ fn create_token(env: &Env, amount: i128, recipient: &Address) -> Address {
     token.address()
 }
 
+/// Build an application payload with the given summary.
+fn sample_payload(env: &Env, summary: &str) -> ApplicationPayload {
+    ApplicationPayload {
+        content_hash: BytesN::from_array(env, &[7u8; 32]),
+        document_uri: String::from_str(env, "ipfs://application"),
+        summary: String::from_str(env, summary),
+    }
+}
+
+/// Mint `amount` of the pool's funding token to `donor` and donate it.
+fn fund_and_donate(
+    env: &Env,
+    client: &ContractClient,
+    pool_id: u32,
+    donor: &Address,
+    amount: u128,
+) {
+    let token = client.get_pool_token(&pool_id);
+    StellarAssetClient::new(env, &token).mint(donor, &(amount as i128));
+    client.donate(&pool_id, donor, &amount);
+}
+
 // ============= ISSUE #460: EMERGENCY WITHDRAWAL GRACE PERIOD VALIDATION TESTS =============
 
 /// Test 1: Execute withdrawal exactly at grace period boundary succeeds
@@ -36,8 +58,18 @@ fn test_emergency_withdrawal_at_grace_period_boundary() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        100_000_000u128,
+    );
     client.request_emergency_withdraw(&admin, &pool_id, &token, &100_000_000i128);
 
     // Advance time exactly to grace period boundary (86400 seconds)
@@ -67,6 +99,9 @@ fn test_emergency_withdrawal_before_grace_period_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     client.request_emergency_withdraw(&admin, &pool_id, &token, &100_000_000i128);
@@ -97,10 +132,20 @@ fn test_grace_period_calculation_with_different_timestamps() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Set initial timestamp to a non-zero value
     env.ledger().set_timestamp(1000);
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        100_000_000u128,
+    );
     client.request_emergency_withdraw(&admin, &pool_id, &token, &100_000_000i128);
 
     // Advance time past grace period (1000 + 86400 + 1 = 87401)
@@ -130,8 +175,18 @@ fn test_emergency_withdrawal_token_transfer() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        100_000_000u128,
+    );
     client.request_emergency_withdraw(&admin, &pool_id, &token, &withdrawal_amount);
 
     // Advance time past grace period
@@ -171,6 +226,9 @@ fn test_contribute_to_active_pool_succeeds() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Pool is in Active state by default - should succeed
@@ -199,19 +257,50 @@ fn test_contribute_to_closed_pool_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
-    // Transition to Disbursed so close_pool is allowed, then close the pool
-    client.set_pool_state(&pool_id, &PoolState::Disbursed);
+    // Cancel so close_pool is allowed, then close the pool
+    client.cancel_pool(&pool_id, &creator);
     client.close_pool(&pool_id);
 
     // Should fail with "Pool is closed"
     client.donate_with_token(&pool_id, &donor, &token, &100_000_000i128);
 }
 
-// NOTE: Tests for Paused, Completed, Cancelled, and Disbursed states require set_pool_state
-// which has SDK limitations with enum parameters. These will be added when the real
-// implementation is available from the dependent issue.
+/// Test 3: Contribute to Paused pool fails, and succeeds again after resume
+#[test]
+fn test_contribute_to_paused_pool_fails_until_resumed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let token = create_token(&env, 100_000_000i128, &donor);
+
+    let pool_id = client.create_pool(
+        &creator,
+        &String::from_str(&env, "Paused Pool"),
+        &String::from_str(&env, "Test"),
+        &1_000_000_000u128,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
+    );
+
+    client.pause_pool(&pool_id, &creator);
+    let result = client.try_donate_with_token(&pool_id, &donor, &token, &100_000_000i128);
+    assert_eq!(result, Err(Ok(ContractError::InvalidPoolState.into())));
+
+    client.resume_pool(&pool_id, &creator);
+    client.donate_with_token(&pool_id, &donor, &token, &100_000_000i128);
+    assert_eq!(client.get_pool(&pool_id).3, 100_000_000u128);
+}
 
 // ============= ISSUE #459: COMPREHENSIVE TESTS FOR EMERGENCY WITHDRAWAL AUTHORIZATION =============
 
@@ -234,6 +323,9 @@ fn test_valid_admin_requests_emergency_withdrawal() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Valid admin should successfully request emergency withdrawal
@@ -268,6 +360,9 @@ fn test_non_admin_request_emergency_withdrawal_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Non-admin should fail with Auth Error
@@ -294,6 +389,9 @@ fn test_duplicate_emergency_withdrawal_request_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // First request should succeed
@@ -323,6 +421,9 @@ fn test_execute_emergency_withdraw_before_grace_period_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     client.request_emergency_withdraw(&admin, &pool_id, &token, &100_000_000i128);
@@ -352,6 +453,9 @@ fn test_zero_amount_contribution_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Zero amount should fail with InvalidAmount
@@ -377,6 +481,9 @@ fn test_negative_amount_contribution_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Negative amount should fail with InvalidAmount
@@ -402,6 +509,9 @@ fn test_maximum_i128_amount_contribution_succeeds() {
         &String::from_str(&env, "Test"),
         &(i128::MAX as u128),
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Maximum i128 amount should succeed if balance allows
@@ -430,6 +540,9 @@ fn test_contribution_exceeding_balance_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
     );
 
     // Try to contribute more than balance - should fail with token transfer error
@@ -454,17 +567,23 @@ fn test_close_disbursed_pool_succeeds() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Disbursed
-    client.set_pool_state(&pool_id, &PoolState::Disbursed);
+    // Move pool through Completed to Disbursed
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.mark_completed(&pool_id, &creator);
+    client.mark_disbursed(&pool_id, &admin);
 
     // Close should succeed for Disbursed pool
     client.close_pool(&pool_id);
 
     // Verify closed state persists
     let pool = client.get_pool(&pool_id);
-    assert_eq!(pool.4, true);
+    assert!(pool.4);
 }
 
 /// Test 2: Close pool in Cancelled state succeeds
@@ -483,17 +602,20 @@ fn test_close_cancelled_pool_succeeds() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Cancelled
-    client.set_pool_state(&pool_id, &PoolState::Cancelled);
+    // Sponsor cancels the pool
+    client.cancel_pool(&pool_id, &creator);
 
     // Close should succeed for Cancelled pool
     client.close_pool(&pool_id);
 
     // Verify closed state persists
     let pool = client.get_pool(&pool_id);
-    assert_eq!(pool.4, true);
+    assert!(pool.4);
 }
 
 /// Test 3: Close pool in Active state fails with PoolNotDisbursedOrRefunded error
@@ -513,6 +635,9 @@ fn test_close_active_pool_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
     // Pool is in Active state by default - should fail
@@ -536,10 +661,13 @@ fn test_close_paused_pool_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Paused
-    client.set_pool_state(&pool_id, &PoolState::Paused);
+    // Sponsor pauses the pool
+    client.pause_pool(&pool_id, &creator);
 
     // Close should fail for Paused pool
     client.close_pool(&pool_id);
@@ -562,10 +690,13 @@ fn test_close_completed_pool_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Completed
-    client.set_pool_state(&pool_id, &PoolState::Completed);
+    // Sponsor marks the pool completed
+    client.mark_completed(&pool_id, &creator);
 
     // Close should fail for Completed pool
     client.close_pool(&pool_id);
@@ -588,10 +719,15 @@ fn test_close_already_closed_pool_fails() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Closed
-    client.set_pool_state(&pool_id, &PoolState::Closed);
+    // Cancel and close the pool once
+    client.cancel_pool(&pool_id, &creator);
+    client.close_pool(&pool_id);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Closed);
 
     // Close should fail for already Closed pool
     client.close_pool(&pool_id);
@@ -613,19 +749,2577 @@ fn test_closed_state_persists() {
         &String::from_str(&env, "Test"),
         &1_000_000_000u128,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
-    // Set pool state to Disbursed
-    client.set_pool_state(&pool_id, &PoolState::Disbursed);
+    // Move pool through Completed to Disbursed
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.mark_completed(&pool_id, &creator);
+    client.mark_disbursed(&pool_id, &admin);
 
     // Close the pool
     client.close_pool(&pool_id);
 
     // Verify is_closed returns true via get_pool
     let pool = client.get_pool(&pool_id);
-    assert_eq!(pool.4, true);
+    assert!(pool.4);
 
     // Verify state persists across multiple reads
     let pool2 = client.get_pool(&pool_id);
-    assert_eq!(pool2.4, true);
+    assert!(pool2.4);
+}
+
+// ============= POOL STATE MACHINE TRANSITION TESTS =============
+
+fn setup_state_machine_pool(env: &Env) -> (ContractClient<'_>, Address, Address, u32) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    client.set_admin(&admin);
+    let pool_id = client.create_pool(
+        &creator,
+        &String::from_str(env, "State Machine Pool"),
+        &String::from_str(env, "Test"),
+        &1_000_000_000u128,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(env),
+        &None,
+    );
+    (client, admin, creator, pool_id)
+}
+
+/// Test 1: Full happy path Active -> Completed -> Disbursed -> Closed
+#[test]
+fn test_pool_state_machine_happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, creator, pool_id) = setup_state_machine_pool(&env);
+
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+    client.mark_completed(&pool_id, &creator);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+    client.mark_disbursed(&pool_id, &admin);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Disbursed);
+    client.close_pool(&pool_id);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Closed);
+}
+
+/// Test 2: Edges outside the transition table are rejected
+#[test]
+fn test_pool_state_machine_rejects_invalid_edges() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, creator, pool_id) = setup_state_machine_pool(&env);
+
+    let invalid = Err(Ok(ContractError::InvalidTransition.into()));
+
+    // Active pools cannot be resumed or disbursed
+    assert_eq!(client.try_resume_pool(&pool_id, &creator), invalid);
+    assert_eq!(client.try_mark_disbursed(&pool_id, &admin), invalid);
+
+    // Cancelled is terminal apart from closing
+    client.cancel_pool(&pool_id, &creator);
+    assert_eq!(client.try_pause_pool(&pool_id, &creator), invalid);
+    assert_eq!(client.try_mark_completed(&pool_id, &creator), invalid);
+    assert_eq!(client.try_cancel_pool(&pool_id, &admin), invalid);
+}
+
+/// Test 3: A third party cannot move a pool between states
+#[test]
+fn test_pool_state_machine_rejects_stranger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, pool_id) = setup_state_machine_pool(&env);
+
+    let stranger = Address::generate(&env);
+    let unauthorized = Err(Ok(ContractError::UnauthorizedTransition.into()));
+
+    assert_eq!(client.try_pause_pool(&pool_id, &stranger), unauthorized);
+    assert_eq!(client.try_cancel_pool(&pool_id, &stranger), unauthorized);
+    assert_eq!(client.try_mark_completed(&pool_id, &stranger), unauthorized);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+}
+
+/// Test 4: Admin-only edges reject the sponsor
+#[test]
+fn test_pool_state_machine_admin_only_edges() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, creator, pool_id) = setup_state_machine_pool(&env);
+
+    let unauthorized = Err(Ok(ContractError::UnauthorizedTransition.into()));
+
+    client.mark_completed(&pool_id, &creator);
+    assert_eq!(client.try_mark_disbursed(&pool_id, &creator), unauthorized);
+    assert_eq!(client.try_cancel_pool(&pool_id, &creator), unauthorized);
+
+    client.cancel_pool(&pool_id, &admin);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Cancelled);
+}
+
+/// Test 5: Admin can pause and resume a pool on the sponsor's behalf
+#[test]
+fn test_pool_state_machine_admin_pause_and_resume() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _creator, pool_id) = setup_state_machine_pool(&env);
+
+    client.pause_pool(&pool_id, &admin);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Paused);
+    client.resume_pool(&pool_id, &admin);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+}
+
+/// Test 6: Transitions require the caller's signature
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_pool_state_machine_requires_caller_auth() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let pool_id = client.create_pool(
+        &creator,
+        &String::from_str(&env, "State Machine Pool"),
+        &String::from_str(&env, "Test"),
+        &1_000_000_000u128,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
+    );
+    client.cancel_pool(&pool_id, &creator);
+}
+
+/// Test 7: Applications, decisions and payouts need an Active or Completed pool
+#[test]
+fn test_pool_state_gates_applications_and_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let sponsor = client.get_pool(&pool_id).1;
+    let data = sample_payload(&env, "Application");
+    let applicant = Address::generate(&env);
+    let student = Address::generate(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 600u128);
+    approve_student(&env, &client, pool_id, &student, 300);
+    client.apply_to_pool(&pool_id, &applicant, &data);
+
+    let invalid = Err(Ok(ContractError::InvalidPoolState.into()));
+    client.pause_pool(&pool_id, &sponsor);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &Address::generate(&env), &data),
+        invalid
+    );
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &applicant, &true, &100i128),
+        invalid
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        invalid
+    );
+    assert_eq!(
+        client.try_verify_milestone(&pool_id, &school, &student, &0, &true),
+        invalid
+    );
+    assert_eq!(
+        client.try_withdraw_unallocated_funds(&pool_id, &token),
+        invalid
+    );
+
+    client.resume_pool(&pool_id, &sponsor);
+    client.mark_completed(&pool_id, &sponsor);
+    client.apply_to_pool(&pool_id, &Address::generate(&env), &data);
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+
+    client.mark_disbursed(&pool_id, &admin);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &Address::generate(&env), &data),
+        invalid
+    );
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &applicant, &true, &100i128),
+        invalid
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        invalid
+    );
+    client.withdraw_unallocated_funds(&pool_id, &token);
+
+    let closed = Err(Ok(ContractError::PoolIsClosed.into()));
+    client.close_pool(&pool_id);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &Address::generate(&env), &data),
+        closed
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        closed
+    );
+    assert_eq!(
+        client.try_withdraw_unallocated_funds(&pool_id, &token),
+        closed
+    );
+
+    // Funds left in a cancelled pool stay available for donor refunds
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 600u128);
+    approve_student(&env, &client, pool_id, &student, 300);
+    client.apply_to_pool(&pool_id, &applicant, &data);
+    client.cancel_pool(&pool_id, &client.get_pool(&pool_id).1);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &Address::generate(&env), &data),
+        invalid
+    );
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &applicant, &true, &100i128),
+        invalid
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        invalid
+    );
+    assert_eq!(
+        client.try_withdraw_unallocated_funds(&pool_id, &token),
+        invalid
+    );
+}
+
+// ============= POOL FUNDING TOKEN BINDING TESTS =============
+
+fn setup_token_bound_pool(env: &Env) -> (ContractClient<'_>, Address, Address, Address, u32) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let school = Address::generate(env);
+    let token = create_token(env, 1_000_000_000i128, &contract_id);
+    client.set_admin(&admin);
+    client.register_school(&school, &BytesN::from_array(env, &[1u8; 32]));
+    let pool_id = client.create_pool_for_school(
+        &creator,
+        &String::from_str(env, "Token Bound Pool"),
+        &String::from_str(env, "Test"),
+        &1_000_000_000u128,
+        &school,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
+    );
+    (client, admin, creator, token, pool_id)
+}
+
+/// Apply `student` to `pool_id` and have the linked school approve `amount`.
+fn approve_student(
+    env: &Env,
+    client: &ContractClient,
+    pool_id: u32,
+    student: &Address,
+    amount: i128,
+) {
+    let school = client.get_pool_school(&pool_id);
+    client.apply_to_pool(&pool_id, student, &sample_payload(env, "Application"));
+    client.approve_application(&pool_id, &school, student, &true, &amount);
+}
+
+/// Test 1: The funding token is exposed through the pool getters
+#[test]
+fn test_pool_token_exposed_by_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, token, pool_id) = setup_token_bound_pool(&env);
+
+    assert_eq!(client.get_pool_token(&pool_id), token);
+    assert_eq!(client.get_pool(&pool_id).6, token);
+}
+
+/// Test 2: Donations in any other token are rejected
+#[test]
+fn test_donate_with_mismatched_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    let donor = Address::generate(&env);
+    let other_token = create_token(&env, 100_000_000i128, &donor);
+    let result = client.try_donate_with_token(&pool_id, &donor, &other_token, &100_000_000i128);
+    assert_eq!(result, Err(Ok(ContractError::TokenMismatch.into())));
+}
+
+/// Test 3: Claims, withdrawals, refunds and emergency requests reject other tokens
+#[test]
+fn test_outbound_transfers_with_mismatched_token_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Keep entries live across the refund grace period jump below
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 50_000;
+        li.max_entry_ttl = 100_000;
+    });
+    let (client, admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    let donor = Address::generate(&env);
+    let student = Address::generate(&env);
+    let other_token = create_token(&env, 1_000_000_000i128, &client.address);
+    let mismatch = Err(Ok(ContractError::TokenMismatch.into()));
+
+    fund_and_donate(&env, &client, pool_id, &donor, 500_000_000u128);
+    approve_student(&env, &client, pool_id, &student, 100_000_000);
+
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100_000_000i128, &other_token),
+        mismatch
+    );
+    assert_eq!(
+        client.try_withdraw_unallocated_funds(&pool_id, &other_token),
+        mismatch
+    );
+    assert_eq!(
+        client.try_request_emergency_withdraw(&admin, &pool_id, &other_token, &1i128),
+        mismatch
+    );
+
+    client.set_pool_deadline(&pool_id, &10);
+    env.ledger().set_sequence_number(10 + 17_280);
+    assert_eq!(
+        client.try_refund_donation(&pool_id, &donor, &other_token),
+        mismatch
+    );
+}
+
+/// Test 4: Protocol fees are tracked per token
+#[test]
+fn test_protocol_fees_are_tracked_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _creator, token, pool_id) = setup_token_bound_pool(&env);
+
+    let student = Address::generate(&env);
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        500_000_000u128,
+    );
+    approve_student(&env, &client, pool_id, &student, 100_000_000);
+    client.claim_funds(&student, &pool_id, &100_000_000i128, &token);
+
+    let other_token = create_token(&env, 1_000_000_000i128, &client.address);
+    assert_eq!(
+        client.try_claim_protocol_fees(&admin, &other_token),
+        Err(Ok(ContractError::NoUnclaimedFees.into()))
+    );
+    assert_eq!(client.claim_protocol_fees(&admin, &token), 1_000_000i128);
+}
+
+// ============= PER-POOL BALANCE LEDGER TESTS =============
+
+/// Test 1: Inbound donations and outbound claims move the pool balance
+#[test]
+fn test_pool_balance_tracks_donations_and_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, token, pool_id) = setup_token_bound_pool(&env);
+
+    let student = Address::generate(&env);
+    assert_eq!(client.get_pool_balance(&pool_id, &token), 0);
+
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        300_000_000u128,
+    );
+    assert_eq!(client.get_pool_balance(&pool_id, &token), 300_000_000i128);
+
+    approve_student(&env, &client, pool_id, &student, 100_000_000);
+    client.claim_funds(&student, &pool_id, &100_000_000i128, &token);
+    assert_eq!(client.get_pool_balance(&pool_id, &token), 200_000_000i128);
+}
+
+/// Test 2: A pool cannot spend another pool's deposits held in the same token
+#[test]
+fn test_pool_cannot_spend_other_pool_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, creator, token, pool_1) = setup_token_bound_pool(&env);
+
+    let pool_2 = client.create_pool_for_school(
+        &creator,
+        &String::from_str(&env, "Second Pool"),
+        &String::from_str(&env, "Test"),
+        &1_000_000_000u128,
+        &client.get_pool_school(&pool_1),
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &token,
+        &None,
+    );
+
+    fund_and_donate(
+        &env,
+        &client,
+        pool_1,
+        &Address::generate(&env),
+        10_000_000u128,
+    );
+    fund_and_donate(
+        &env,
+        &client,
+        pool_2,
+        &Address::generate(&env),
+        500_000_000u128,
+    );
+
+    // Inflate pool 1's view of collected funds without depositing tokens
+    env.as_contract(&client.address, || {
+        let mut pool: Pool = env.storage().persistent().get(&pool_1).unwrap();
+        pool.collected = 500_000_000u128;
+        env.storage().persistent().set(&pool_1, &pool);
+    });
+
+    // Approvals can only reserve what pool 1 itself holds
+    let student = Address::generate(&env);
+    let school = client.get_pool_school(&pool_1);
+    client.apply_to_pool(&pool_1, &student, &sample_payload(&env, "Application"));
+    let result =
+        client.try_approve_application(&pool_1, &school, &student, &true, &100_000_000i128);
+    assert_eq!(
+        result,
+        Err(Ok(ContractError::InsufficientUnreservedFunds.into()))
+    );
+    assert_eq!(client.get_pool_balance(&pool_2, &token), 500_000_000i128);
+}
+
+/// Test 3: Emergency withdrawals are limited to the pool's own balance
+#[test]
+fn test_emergency_withdraw_limited_to_pool_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _creator, token, pool_id) = setup_token_bound_pool(&env);
+
+    fund_and_donate(
+        &env,
+        &client,
+        pool_id,
+        &Address::generate(&env),
+        50_000_000u128,
+    );
+    client.request_emergency_withdraw(&admin, &pool_id, &token, &100_000_000i128);
+    env.ledger().set_timestamp(86_400);
+
+    let result = client.try_execute_emergency_withdraw(&pool_id);
+    assert_eq!(
+        result,
+        Err(Ok(ContractError::InsufficientPoolBalance.into()))
+    );
+}
+
+// ============= POOL METADATA TESTS =============
+
+fn sample_metadata(env: &Env) -> PoolMetadata {
+    let mut social_urls = Vec::new(env);
+    social_urls.push_back(String::from_str(env, "https://x.com/nevo"));
+    let mut tags = Vec::new(env);
+    tags.push_back(String::from_str(env, "stem"));
+    tags.push_back(String::from_str(env, "undergrad"));
+    PoolMetadata {
+        title: String::from_str(env, "Token Bound Pool"),
+        description: String::from_str(env, "Scholarships for STEM students"),
+        website_url: String::from_str(env, "https://nevo.example"),
+        social_urls,
+        cover_image_hash: String::from_str(
+            env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        category: PoolCategory::Scholarship,
+        tags,
+    }
+}
+
+/// Test 1: New pools start with empty metadata that the sponsor can fill in
+#[test]
+fn test_update_pool_metadata_by_sponsor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    let initial = client.get_pool_metadata(&pool_id);
+    assert_eq!(initial.title, String::from_str(&env, "Token Bound Pool"));
+    assert_eq!(initial.description, String::from_str(&env, "Test"));
+    assert_eq!(initial.category, PoolCategory::Other);
+    assert!(initial.website_url.is_empty());
+    assert!(initial.tags.is_empty());
+
+    let metadata = sample_metadata(&env);
+    client.update_pool_metadata(&pool_id, &metadata);
+    assert_eq!(
+        env.auths()[0].0,
+        creator,
+        "sponsor must authorize metadata updates"
+    );
+    assert_eq!(client.get_pool_metadata(&pool_id), metadata);
+}
+
+/// Test 2: Only the sponsor may update metadata
+#[test]
+fn test_update_pool_metadata_requires_sponsor_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+    env.set_auths(&[]);
+
+    let result = client.try_update_pool_metadata(&pool_id, &sample_metadata(&env));
+    assert!(result.is_err());
+}
+
+/// Test 3: Oversized fields are rejected with typed errors
+#[test]
+fn test_update_pool_metadata_enforces_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    let long_url = String::from_str(&env, &"u".repeat(MAX_URL_LENGTH + 1));
+
+    let mut metadata = sample_metadata(&env);
+    metadata.website_url = long_url.clone();
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::UrlTooLong.into()))
+    );
+
+    let mut metadata = sample_metadata(&env);
+    metadata.social_urls.push_back(long_url);
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::UrlTooLong.into()))
+    );
+
+    let mut metadata = sample_metadata(&env);
+    for _ in 0..MAX_SOCIAL_URLS {
+        metadata
+            .social_urls
+            .push_back(String::from_str(&env, "https://example.org"));
+    }
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::TooManySocialUrls.into()))
+    );
+
+    let mut metadata = sample_metadata(&env);
+    metadata.cover_image_hash = String::from_str(&env, &"h".repeat(MAX_IMAGE_HASH_LENGTH + 1));
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::ImageHashTooLong.into()))
+    );
+
+    let mut metadata = sample_metadata(&env);
+    for _ in 0..MAX_TAGS {
+        metadata.tags.push_back(String::from_str(&env, "tag"));
+    }
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::TooManyTags.into()))
+    );
+
+    let mut metadata = sample_metadata(&env);
+    metadata.tags.push_back(String::from_str(&env, ""));
+    assert_eq!(
+        client.try_update_pool_metadata(&pool_id, &metadata),
+        Err(Ok(ContractError::InvalidTag.into()))
+    );
+
+    // Nothing was written by the rejected updates
+    assert_eq!(
+        client.get_pool_metadata(&pool_id).category,
+        PoolCategory::Other
+    );
+}
+
+// ============= POOL PARAMETER UPDATE TESTS =============
+
+/// Test 1: Sponsor can change the goal and every change is kept in history
+#[test]
+fn test_update_pool_goal_records_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    env.ledger().set_timestamp(500);
+    client.update_pool_goal(&pool_id, &2_000_000_000u128);
+    let (old_goal, new_goal): (u128, u128) = env.events().all().last().unwrap().2.into_val(&env);
+    assert_eq!((old_goal, new_goal), (1_000_000_000u128, 2_000_000_000u128));
+    let (_, _, goal, _, _, _, _) = client.get_pool(&pool_id);
+    assert_eq!(goal, 2_000_000_000u128);
+
+    client.update_pool_goal(&pool_id, &1_500_000_000u128);
+
+    let history = client.get_parameter_history(&pool_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history.get(0).unwrap(),
+        ParameterChange {
+            parameter: PoolParameter::Goal,
+            old_value: 1_000_000_000u128,
+            new_value: 2_000_000_000u128,
+            timestamp: 500,
+        }
+    );
+    assert_eq!(history.get(1).unwrap().old_value, 2_000_000_000u128);
+    assert_eq!(history.get(1).unwrap().new_value, 1_500_000_000u128);
+}
+
+/// Test 2: Goal cannot drop below collected; lowering it to collected completes the pool
+#[test]
+fn test_update_pool_goal_respects_collected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 400u128);
+
+    assert_eq!(
+        client.try_update_pool_goal(&pool_id, &399u128),
+        Err(Ok(ContractError::GoalBelowCollected.into()))
+    );
+
+    client.update_pool_goal(&pool_id, &400u128);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+
+    // Completed pools keep their goal
+    assert_eq!(
+        client.try_update_pool_goal(&pool_id, &800u128),
+        Err(Ok(ContractError::InvalidPoolState.into()))
+    );
+}
+
+/// Test 3: Deadline may move earlier only while there are no applications
+#[test]
+fn test_update_application_deadline_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        client.try_update_application_deadline(&pool_id, &1_000u64),
+        Err(Ok(ContractError::InvalidDeadline.into()))
+    );
+
+    client.update_application_deadline(&pool_id, &50_000u64);
+    assert_eq!(client.get_pool(&pool_id).5, 50_000u64);
+
+    client.apply_to_pool(
+        &pool_id,
+        &Address::generate(&env),
+        &sample_payload(&env, "Application"),
+    );
+    assert_eq!(
+        client.try_update_application_deadline(&pool_id, &40_000u64),
+        Err(Ok(ContractError::DeadlineMovedEarlier.into()))
+    );
+
+    client.update_application_deadline(&pool_id, &80_000u64);
+    let history = client.get_parameter_history(&pool_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history.get(1).unwrap().parameter,
+        PoolParameter::ApplicationDeadline
+    );
+    assert_eq!(history.get(1).unwrap().old_value, 50_000u128);
+    assert_eq!(history.get(1).unwrap().new_value, 80_000u128);
+}
+
+/// Test 4: Only the sponsor may edit pool parameters
+#[test]
+fn test_update_pool_parameters_require_sponsor_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _creator, _token, pool_id) = setup_token_bound_pool(&env);
+    env.set_auths(&[]);
+
+    assert!(client.try_update_pool_goal(&pool_id, &5u128).is_err());
+    assert!(client
+        .try_update_application_deadline(&pool_id, &200_000u64)
+        .is_err());
+    assert_eq!(client.get_parameter_history(&pool_id).len(), 0);
+}
+
+// ============= APPLICATION WINDOW TESTS =============
+
+fn setup_school_pool(env: &Env) -> (ContractClient<'_>, Address, Address, u32) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let school = Address::generate(env);
+    client.set_admin(&admin);
+    client.register_school(&school, &BytesN::from_array(env, &[1u8; 32]));
+    let pool_id = client.create_pool_for_school(
+        &creator,
+        &String::from_str(env, "School Pool"),
+        &String::from_str(env, "Test"),
+        &1_000u128,
+        &school,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &create_token(env, 0, &contract_id),
+        &None,
+    );
+    (client, admin, school, pool_id)
+}
+
+/// Test 1: Applications are accepted up to the deadline and rejected after it
+#[test]
+fn test_apply_after_application_deadline_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let data = sample_payload(&env, "Application");
+
+    env.ledger().set_timestamp(100_000);
+    client.apply_to_pool(&pool_id, &Address::generate(&env), &data);
+
+    env.ledger().set_timestamp(100_001);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &Address::generate(&env), &data),
+        Err(Ok(ContractError::ApplicationDeadlinePassed.into()))
+    );
+}
+
+/// Test 2: Approvals are closed once the review window ends
+#[test]
+fn test_approve_after_review_deadline_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    let data = sample_payload(&env, "Application");
+    client.apply_to_pool(&pool_id, &early, &data);
+    client.apply_to_pool(&pool_id, &late, &data);
+
+    assert_eq!(
+        client.try_set_review_deadline(&pool_id, &100_000u64),
+        Err(Ok(ContractError::InvalidDeadline.into()))
+    );
+    client.set_review_deadline(&pool_id, &150_000u64);
+    assert_eq!(client.get_review_deadline(&pool_id), Some(150_000u64));
+
+    env.ledger().set_timestamp(150_000);
+    client.approve_application(&pool_id, &school, &early, &true, &100i128);
+
+    env.ledger().set_timestamp(150_001);
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &late, &true, &100i128),
+        Err(Ok(ContractError::ReviewWindowClosed.into()))
+    );
+
+    let history = client.get_parameter_history(&pool_id);
+    assert_eq!(
+        history.get(0).unwrap().parameter,
+        PoolParameter::ReviewDeadline
+    );
+    assert_eq!(history.get(0).unwrap().old_value, 0u128);
+}
+
+/// Test 3: Application deadline cannot be pushed past the review deadline
+#[test]
+fn test_application_deadline_stays_before_review_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+
+    client.set_review_deadline(&pool_id, &150_000u64);
+    assert_eq!(
+        client.try_update_application_deadline(&pool_id, &150_000u64),
+        Err(Ok(ContractError::InvalidDeadline.into()))
+    );
+    client.update_application_deadline(&pool_id, &149_999u64);
+}
+
+/// Test 4: Pool phase follows the ledger timestamp
+#[test]
+fn test_get_pool_phase_follows_timeline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Fundraising);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Applications);
+
+    env.ledger().set_timestamp(100_001);
+    // Without a review window the pool goes straight to disbursement
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Disbursement);
+
+    env.ledger().set_timestamp(0);
+    client.set_review_deadline(&pool_id, &150_000u64);
+    env.ledger().set_timestamp(100_001);
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Review);
+
+    env.ledger().set_timestamp(150_001);
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Disbursement);
+
+    // Cancelled and closed pools report the terminal phase before any deadline
+    env.ledger().set_timestamp(0);
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    client.cancel_pool(&pool_id, &client.get_pool(&pool_id).1);
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Ended);
+    client.close_pool(&pool_id);
+    assert_eq!(client.get_pool_phase(&pool_id), PoolPhase::Ended);
+}
+
+// ============= APPLICATION STATUS TRANSITION TESTS =============
+
+/// Test 1: Every status change emits the old and new status
+#[test]
+fn test_application_status_change_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    let student = Address::generate(&env);
+
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    client.approve_application(&pool_id, &school, &student, &true, &100i128);
+
+    let (_, topics, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("app_stat").to_val())
+        })
+        .expect("status change event not emitted");
+    let event_pool_id: u32 = topics.get(1).unwrap().into_val(&env);
+    let (event_student, old_status, new_status): (
+        Address,
+        Option<ApplicationStatus>,
+        ApplicationStatus,
+    ) = data.into_val(&env);
+    assert_eq!(event_pool_id, pool_id);
+    assert_eq!(event_student, student);
+    assert_eq!(old_status, Some(ApplicationStatus::Pending));
+    assert_eq!(new_status, ApplicationStatus::Approved);
+}
+
+/// Test 2: Decided applications cannot be decided again
+#[test]
+fn test_decided_application_cannot_be_redecided() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    client.approve_application(&pool_id, &school, &student, &false, &0i128);
+
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &student, &true, &100i128),
+        Err(Ok(ContractError::InvalidStatusTransition.into()))
+    );
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Rejected)
+    );
+}
+
+/// Test 3: Students can withdraw only their own application
+#[test]
+fn test_withdraw_application_requires_student_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+
+    env.set_auths(&[]);
+    assert!(client.try_withdraw_application(&pool_id, &student).is_err());
+
+    env.mock_all_auths();
+    client.withdraw_application(&pool_id, &student);
+    assert_eq!(env.auths()[0].0, student);
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Withdrawn)
+    );
+}
+
+/// Test 4: Only the admin can revoke, and only approved applications
+#[test]
+fn test_revoke_application_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_school_pool(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    let student = Address::generate(&env);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+
+    assert_eq!(
+        client.try_revoke_application(&pool_id, &admin, &student),
+        Err(Ok(ContractError::InvalidStatusTransition.into()))
+    );
+
+    client.approve_application(&pool_id, &school, &student, &true, &100i128);
+    assert_eq!(
+        client.try_revoke_application(&pool_id, &school, &student),
+        Err(Ok(ContractError::UnauthorizedAdmin.into()))
+    );
+
+    client.revoke_application(&pool_id, &admin, &student);
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Revoked)
+    );
+    assert_eq!(
+        client.try_revoke_application(&pool_id, &admin, &Address::generate(&env)),
+        Err(Ok(ContractError::StudentHasNotApplied.into()))
+    );
+}
+
+// ============= APPROVED AMOUNT TESTS =============
+
+/// Test 1: Approval stores the Application and claims are capped by it
+#[test]
+fn test_claims_capped_by_approved_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    client.approve_application(&pool_id, &school, &student, &true, &400i128);
+    assert_eq!(
+        client.get_application(&pool_id, &student),
+        Some(Application {
+            approved_amount: 400,
+            amount_claimed: 0,
+        })
+    );
+
+    client.claim_funds(&student, &pool_id, &300i128, &token);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &101i128, &token),
+        Err(Ok(ContractError::ExceedsApprovedAmount.into()))
+    );
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 400);
+}
+
+/// Test 2: Approvals must carry a positive amount
+#[test]
+fn test_approve_with_non_positive_amount_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &student, &true, &0i128),
+        Err(Ok(ContractError::InvalidApprovedAmount.into()))
+    );
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Pending)
+    );
+}
+
+/// Test 3: The school can only lower the amount, and only before any claim
+#[test]
+fn test_adjust_approved_amount_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    client.approve_application(&pool_id, &school, &student, &true, &500i128);
+
+    assert_eq!(
+        client.try_adjust_approved_amount(&pool_id, &Address::generate(&env), &student, &300i128),
+        Err(Ok(ContractError::OnlyLinkedSchoolCanApprove.into()))
+    );
+    assert_eq!(
+        client.try_adjust_approved_amount(&pool_id, &school, &student, &500i128),
+        Err(Ok(ContractError::InvalidApprovedAmount.into()))
+    );
+
+    client.adjust_approved_amount(&pool_id, &school, &student, &300i128);
+    let (event_student, old_amount, new_amount): (Address, i128, i128) =
+        env.events().all().last().unwrap().2.into_val(&env);
+    assert_eq!(
+        (event_student, old_amount, new_amount),
+        (student.clone(), 500, 300)
+    );
+    assert_eq!(
+        client
+            .get_application(&pool_id, &student)
+            .unwrap()
+            .approved_amount,
+        300
+    );
+
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+    assert_eq!(
+        client.try_adjust_approved_amount(&pool_id, &school, &student, &200i128),
+        Err(Ok(ContractError::ClaimAlreadyMade.into()))
+    );
+}
+
+// ============= RESERVATION TESTS =============
+
+/// Test 1: Approvals reserve funds and cannot exceed the unreserved balance
+#[test]
+fn test_approvals_limited_to_unreserved_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let data = sample_payload(&env, "Application");
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &first, &data);
+    client.apply_to_pool(&pool_id, &second, &data);
+
+    client.approve_application(&pool_id, &school, &first, &true, &700i128);
+    assert_eq!(client.get_pool_reserved(&pool_id), 700u128);
+
+    assert_eq!(
+        client.try_approve_application(&pool_id, &school, &second, &true, &301i128),
+        Err(Ok(ContractError::InsufficientUnreservedFunds.into()))
+    );
+    client.approve_application(&pool_id, &school, &second, &true, &300i128);
+    assert_eq!(client.get_pool_reserved(&pool_id), 1_000u128);
+}
+
+/// Test 2: Claims, adjustments, withdrawals and revocations release reservations
+#[test]
+fn test_reservations_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let claimer = Address::generate(&env);
+    let adjusted = Address::generate(&env);
+    let withdrawn = Address::generate(&env);
+    let data = sample_payload(&env, "Application");
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &claimer, &data);
+    client.apply_to_pool(&pool_id, &adjusted, &data);
+    client.apply_to_pool(&pool_id, &withdrawn, &data);
+    client.approve_application(&pool_id, &school, &claimer, &true, &400i128);
+    client.approve_application(&pool_id, &school, &adjusted, &true, &300i128);
+    client.approve_application(&pool_id, &school, &withdrawn, &true, &300i128);
+
+    client.claim_funds(&claimer, &pool_id, &100i128, &token);
+    assert_eq!(client.get_pool_reserved(&pool_id), 900u128);
+
+    client.adjust_approved_amount(&pool_id, &school, &adjusted, &200i128);
+    assert_eq!(client.get_pool_reserved(&pool_id), 800u128);
+
+    client.withdraw_application(&pool_id, &withdrawn);
+    assert_eq!(client.get_pool_reserved(&pool_id), 500u128);
+
+    // Only the unclaimed part of a revoked approval is released
+    client.revoke_application(&pool_id, &admin, &claimer);
+    assert_eq!(client.get_pool_reserved(&pool_id), 200u128);
+}
+
+/// Test 3: Refunds and emergency withdrawals cannot take reserved funds
+#[test]
+fn test_refunds_and_emergency_withdrawals_leave_reservations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| {
+        ledger.min_persistent_entry_ttl = 100_000;
+        ledger.min_temp_entry_ttl = 100_000;
+    });
+    let (client, admin, _school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let donor = Address::generate(&env);
+    let student = Address::generate(&env);
+
+    fund_and_donate(&env, &client, pool_id, &donor, 1_000u128);
+    client.set_pool_deadline(&pool_id, &10u32);
+    approve_student(&env, &client, pool_id, &student, 600);
+
+    env.ledger().set_sequence_number(10 + 17_280);
+    assert_eq!(
+        client.try_refund_donation(&pool_id, &donor, &token),
+        Err(Ok(ContractError::InsufficientUnreservedFunds.into()))
+    );
+
+    client.request_emergency_withdraw(&admin, &pool_id, &token, &500i128);
+    env.ledger().set_timestamp(86_400);
+    assert_eq!(
+        client.try_execute_emergency_withdraw(&pool_id),
+        Err(Ok(ContractError::InsufficientUnreservedFunds.into()))
+    );
+
+    client.claim_funds(&student, &pool_id, &600i128, &token);
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 600);
+}
+
+// ============= APPLICATION WITHDRAWAL TESTS =============
+
+/// Test 1: Withdrawing releases the reservation, emits an event and allows re-applying
+#[test]
+fn test_withdraw_application_allows_reapply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    let data = sample_payload(&env, "Application");
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &student, &data);
+    client.approve_application(&pool_id, &school, &student, &true, &400i128);
+
+    client.withdraw_application(&pool_id, &student);
+    let (_, topics, data_val) = env.events().all().last().unwrap();
+    assert!(topics
+        .get(0)
+        .unwrap()
+        .shallow_eq(&symbol_short!("app_wdrn").to_val()));
+    let (event_student, released): (Address, i128) = data_val.into_val(&env);
+    assert_eq!((event_student, released), (student.clone(), 400));
+    assert_eq!(client.get_pool_reserved(&pool_id), 0u128);
+    assert_eq!(client.get_application(&pool_id, &student), None);
+
+    client.apply_to_pool(&pool_id, &student, &data);
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Pending)
+    );
+
+    client.withdraw_application(&pool_id, &student);
+    env.ledger().set_timestamp(100_001);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &data),
+        Err(Ok(ContractError::ApplicationDeadlinePassed.into()))
+    );
+}
+
+/// Test 2: Students who already claimed cannot re-apply after withdrawing
+#[test]
+fn test_withdraw_after_claim_blocks_reapply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    let data = sample_payload(&env, "Application");
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &student, &data);
+    client.approve_application(&pool_id, &school, &student, &true, &400i128);
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+
+    client.withdraw_application(&pool_id, &student);
+    let (_, released): (Address, i128) = env.events().all().last().unwrap().2.into_val(&env);
+    assert_eq!(released, 300);
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 100);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &data),
+        Err(Ok(ContractError::DuplicateApplication.into()))
+    );
+}
+
+/// Test 3: Withdrawing clears the active plan, proposals, amendments and evidence but keeps plan history
+#[test]
+fn test_withdraw_application_clears_milestone_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    let original = client.get_milestones(&pool_id, &student);
+    let replacement = Vec::from_array(&env, [sample_milestone(&env, 1_000, 80_000)]);
+    client.submit_milestone_evidence(
+        &pool_id,
+        &student,
+        &0,
+        &BytesN::from_array(&env, &[5u8; 32]),
+    );
+    client.setup_application_milestones(&pool_id, &student, &replacement);
+    client.propose_milestone_amendment(&pool_id, &student, &student, &replacement);
+
+    client.withdraw_application(&pool_id, &student);
+    assert_eq!(client.get_milestones(&pool_id, &student).len(), 0);
+    assert_eq!(client.get_proposed_milestones(&pool_id, &student), None);
+    assert_eq!(client.get_milestone_amendment(&pool_id, &student), None);
+    assert_eq!(client.get_milestone_evidence(&pool_id, &student, &0), None);
+    // Earlier versions stay on record
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 1);
+    assert_eq!(
+        client.get_milestone_plan_at(&pool_id, &student, &1),
+        Some(original.clone())
+    );
+
+    approve_student(&env, &client, pool_id, &student, 1_000);
+    assert_eq!(client.get_milestones(&pool_id, &student).len(), 0);
+    assert_eq!(
+        client.try_approve_milestone_plan(&pool_id, &school, &student),
+        Err(Ok(DisbursementError::MilestonePlanNotFound.into()))
+    );
+    client.setup_application_milestones(&pool_id, &student, &replacement);
+    client.approve_milestone_plan(&pool_id, &school, &student);
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 2);
+    assert_eq!(
+        client.get_milestone_plan_at(&pool_id, &student, &1),
+        Some(original)
+    );
+}
+
+// ============= APPLICATION LISTING TESTS =============
+
+/// Test 1: Applications are listed in submission order with their status
+#[test]
+fn test_list_applications_pages_and_filters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    assert_eq!(client.get_application_count(&pool_id), 0);
+    client.apply_to_pool(&pool_id, &first, &sample_payload(&env, "First"));
+    client.apply_to_pool(&pool_id, &second, &sample_payload(&env, "Second"));
+    client.apply_to_pool(&pool_id, &third, &sample_payload(&env, "Third"));
+    client.approve_application(&pool_id, &school, &second, &false, &0i128);
+    assert_eq!(client.get_application_count(&pool_id), 3);
+
+    let all = client.list_applications(&pool_id, &0u32, &10u32, &None);
+    assert_eq!(all.len(), 3);
+    assert_eq!(
+        all.get(1).unwrap(),
+        ApplicationEntry {
+            index: 2,
+            student: second.clone(),
+            payload: sample_payload(&env, "Second"),
+            status: ApplicationStatus::Rejected,
+        }
+    );
+
+    let page = client.list_applications(&pool_id, &1u32, &1u32, &None);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().student, second);
+
+    let pending =
+        client.list_applications(&pool_id, &0u32, &10u32, &Some(ApplicationStatus::Pending));
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().student, first);
+    assert_eq!(pending.get(1).unwrap().student, third);
+
+    assert_eq!(
+        client
+            .list_applications(&pool_id, &3u32, &10u32, &None)
+            .len(),
+        0
+    );
+}
+
+/// Test 2: Listing an unknown pool fails
+#[test]
+fn test_list_applications_unknown_pool() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_list_applications(&99u32, &0u32, &10u32, &None),
+        Err(Ok(ContractError::PoolNotFound.into()))
+    );
+}
+
+/// Test 3: A re-application supersedes the student's earlier entry
+#[test]
+fn test_list_applications_after_reapply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "First"));
+    client.withdraw_application(&pool_id, &student);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Second"));
+
+    let all = client.list_applications(&pool_id, &0u32, &10u32, &None);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().status, ApplicationStatus::Withdrawn);
+    assert_eq!(all.get(1).unwrap().status, ApplicationStatus::Pending);
+
+    let pending =
+        client.list_applications(&pool_id, &0u32, &10u32, &Some(ApplicationStatus::Pending));
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().index, 2);
+}
+
+// ============= APPLICATION PAYLOAD TESTS =============
+
+/// Test 1: Oversized payload fields are rejected with typed errors
+#[test]
+fn test_apply_rejects_oversized_payload() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+
+    let mut payload = sample_payload(&env, "Application");
+    payload.document_uri = String::from_str(&env, &"u".repeat(257));
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::DocumentUriTooLong.into()))
+    );
+
+    let payload = sample_payload(&env, &"s".repeat(281));
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::SummaryTooLong.into()))
+    );
+
+    let mut payload = sample_payload(&env, &"s".repeat(280));
+    payload.document_uri = String::from_str(&env, &"u".repeat(256));
+    client.apply_to_pool(&pool_id, &student, &payload);
+    assert_eq!(
+        client
+            .list_applications(&pool_id, &0u32, &1u32, &None)
+            .get(0)
+            .unwrap()
+            .payload,
+        payload
+    );
+}
+
+// ============= APPEAL TESTS =============
+
+fn reject_student(env: &Env, client: &ContractClient, pool_id: u32, school: &Address) -> Address {
+    let student = Address::generate(env);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(env, "Application"));
+    client.approve_application(&pool_id, school, &student, &false, &0i128);
+    student
+}
+
+/// Test 1: A granted appeal approves and reserves the application
+#[test]
+fn test_granted_appeal_approves_application() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_school_pool(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    let student = reject_student(&env, &client, pool_id, &school);
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+
+    env.ledger().set_timestamp(1_000);
+    client.file_appeal(&pool_id, &student, &reason);
+    let (event_student, event_reason): (Address, BytesN<32>) =
+        env.events().all().last().unwrap().2.into_val(&env);
+    assert_eq!(
+        (event_student, event_reason),
+        (student.clone(), reason.clone())
+    );
+    assert_eq!(
+        client.try_file_appeal(&pool_id, &student, &reason),
+        Err(Ok(ContractError::AppealAlreadyFiled.into()))
+    );
+
+    client.resolve_appeal(&pool_id, &admin, &student, &true, &400i128);
+    let (_, granted, amount): (Address, bool, i128) =
+        env.events().all().last().unwrap().2.into_val(&env);
+    assert!(granted);
+    assert_eq!(amount, 400);
+
+    assert_eq!(
+        client.get_appeal(&pool_id, &student),
+        Some(Appeal {
+            reason_hash: reason,
+            filed_at: 1_000,
+            status: AppealStatus::Granted,
+        })
+    );
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Approved)
+    );
+    assert_eq!(client.get_pool_reserved(&pool_id), 400u128);
+    assert_eq!(
+        client.try_resolve_appeal(&pool_id, &admin, &student, &false, &0i128),
+        Err(Ok(ContractError::AppealAlreadyResolved.into()))
+    );
+}
+
+/// Test 2: Only the admin or the designated arbitrator can rule, and denial keeps the rejection
+#[test]
+fn test_appeal_ruled_by_arbitrator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = reject_student(&env, &client, pool_id, &school);
+    client.file_appeal(&pool_id, &student, &BytesN::from_array(&env, &[9u8; 32]));
+
+    let arbitrator = Address::generate(&env);
+    assert_eq!(
+        client.try_resolve_appeal(&pool_id, &arbitrator, &student, &false, &0i128),
+        Err(Ok(ContractError::UnauthorizedArbitrator.into()))
+    );
+    client.set_arbitrator(&arbitrator);
+    assert_eq!(client.get_arbitrator(), Some(arbitrator.clone()));
+
+    client.resolve_appeal(&pool_id, &arbitrator, &student, &false, &0i128);
+    assert_eq!(
+        client.get_appeal(&pool_id, &student).unwrap().status,
+        AppealStatus::Denied
+    );
+    assert_eq!(
+        client.get_application_status(&pool_id, &student),
+        Some(ApplicationStatus::Rejected)
+    );
+}
+
+/// Test 3: Appeals must target a rejection and be filed within the window
+#[test]
+fn test_appeal_filing_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_school_pool(&env);
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+
+    let pending = Address::generate(&env);
+    client.apply_to_pool(&pool_id, &pending, &sample_payload(&env, "Application"));
+    assert_eq!(
+        client.try_file_appeal(&pool_id, &pending, &reason),
+        Err(Ok(ContractError::ApplicationNotRejected.into()))
+    );
+    assert_eq!(
+        client.try_resolve_appeal(&pool_id, &admin, &pending, &true, &100i128),
+        Err(Ok(ContractError::AppealNotFound.into()))
+    );
+
+    env.ledger().set_timestamp(10);
+    let late = reject_student(&env, &client, pool_id, &school);
+    env.ledger().set_timestamp(10 + 604_801);
+    assert_eq!(
+        client.try_file_appeal(&pool_id, &late, &reason),
+        Err(Ok(ContractError::AppealWindowClosed.into()))
+    );
+}
+
+// ============= RECIPIENT CAPACITY TESTS =============
+
+fn setup_capped_pool(
+    env: &Env,
+    max_recipients: u32,
+) -> (ContractClient<'_>, Address, Address, u32) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let school = Address::generate(env);
+    client.set_admin(&admin);
+    client.register_school(&school, &BytesN::from_array(env, &[1u8; 32]));
+    let pool_id = client.create_pool_for_school(
+        &Address::generate(env),
+        &String::from_str(env, "Capped Pool"),
+        &String::from_str(env, "Test"),
+        &1_000u128,
+        &school,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &create_token(env, 0, &contract_id),
+        &Some(max_recipients),
+    );
+    fund_and_donate(env, &client, pool_id, &Address::generate(env), 1_000u128);
+    (client, admin, school, pool_id)
+}
+
+/// Test 1: Approvals past the cap are waitlisted in submission order
+#[test]
+fn test_approvals_past_capacity_are_waitlisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_capped_pool(&env, 1);
+    let students = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for student in students.iter() {
+        client.apply_to_pool(&pool_id, student, &sample_payload(&env, "Application"));
+    }
+
+    client.approve_application(&pool_id, &school, &students[0], &true, &300i128);
+    // Decided out of submission order; the waitlist still follows it
+    client.approve_application(&pool_id, &school, &students[2], &true, &300i128);
+    client.approve_application(&pool_id, &school, &students[1], &true, &300i128);
+
+    assert_eq!(client.get_max_recipients(&pool_id), Some(1));
+    assert_eq!(client.get_recipient_count(&pool_id), 1);
+    assert_eq!(client.get_pool_reserved(&pool_id), 300u128);
+    assert_eq!(
+        client.get_application_status(&pool_id, &students[2]),
+        Some(ApplicationStatus::Waitlisted)
+    );
+    let waitlist = client.get_waitlist(&pool_id);
+    assert_eq!(waitlist.len(), 2);
+    assert_eq!(waitlist.get(0).unwrap(), students[1]);
+    assert_eq!(waitlist.get(1).unwrap(), students[2]);
+}
+
+/// Test 2: Revoking or withdrawing an approval promotes the next waitlisted student
+#[test]
+fn test_waitlisted_student_promoted_on_revoke_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, school, pool_id) = setup_capped_pool(&env, 1);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    for student in [&first, &second, &third] {
+        client.apply_to_pool(&pool_id, student, &sample_payload(&env, "Application"));
+        client.approve_application(&pool_id, &school, student, &true, &300i128);
+    }
+
+    client.revoke_application(&pool_id, &admin, &first);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("wl_promo").to_val())
+        })
+        .expect("promotion event not emitted");
+    let (promoted, amount): (Address, i128) = data.into_val(&env);
+    assert_eq!((promoted, amount), (second.clone(), 300));
+    assert_eq!(
+        client.get_application_status(&pool_id, &second),
+        Some(ApplicationStatus::Approved)
+    );
+    assert_eq!(client.get_pool_reserved(&pool_id), 300u128);
+
+    client.withdraw_application(&pool_id, &second);
+    assert_eq!(
+        client.get_application_status(&pool_id, &third),
+        Some(ApplicationStatus::Approved)
+    );
+    assert_eq!(client.get_recipient_count(&pool_id), 1);
+    assert_eq!(client.get_waitlist(&pool_id).len(), 0);
+}
+
+/// Test 3: Waitlisted students can withdraw and leave the queue
+#[test]
+fn test_waitlisted_student_can_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_capped_pool(&env, 1);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    for student in [&first, &second] {
+        client.apply_to_pool(&pool_id, student, &sample_payload(&env, "Application"));
+        client.approve_application(&pool_id, &school, student, &true, &300i128);
+    }
+
+    client.withdraw_application(&pool_id, &second);
+    assert_eq!(client.get_waitlist(&pool_id).len(), 0);
+    assert_eq!(client.get_pool_reserved(&pool_id), 300u128);
+}
+
+/// Test 4: Students blocked on funds keep their place and are promoted once donations arrive
+#[test]
+fn test_waitlist_retries_after_donation_and_keeps_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_capped_pool(&env, 1);
+    let token = client.get_pool_token(&pool_id);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let late = Address::generate(&env);
+    for student in [&first, &second, &late] {
+        client.apply_to_pool(&pool_id, student, &sample_payload(&env, "Application"));
+    }
+
+    client.approve_application(&pool_id, &school, &first, &true, &1_000i128);
+    client.approve_application(&pool_id, &school, &second, &true, &600i128);
+    client.claim_funds(&first, &pool_id, &800i128, &token);
+
+    // A slot opens, but only 200 is unreserved, so the head stays queued
+    client.withdraw_application(&pool_id, &first);
+    assert_eq!(
+        client.get_application_status(&pool_id, &second),
+        Some(ApplicationStatus::Waitlisted)
+    );
+    assert_eq!(client.get_recipient_count(&pool_id), 0);
+
+    // A later approval joins the queue instead of overtaking it
+    client.approve_application(&pool_id, &school, &late, &true, &100i128);
+    assert_eq!(
+        client.get_application_status(&pool_id, &late),
+        Some(ApplicationStatus::Waitlisted)
+    );
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 500u128);
+    assert_eq!(
+        client.get_application_status(&pool_id, &second),
+        Some(ApplicationStatus::Approved)
+    );
+    assert_eq!(client.get_pool_reserved(&pool_id), 600u128);
+    let waitlist = client.get_waitlist(&pool_id);
+    assert_eq!(waitlist.len(), 1);
+    assert_eq!(waitlist.get(0).unwrap(), late);
+}
+
+/// Test 5: A cap of zero is rejected at creation
+#[test]
+fn test_zero_max_recipients_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_create_pool(
+            &Address::generate(&env),
+            &String::from_str(&env, "Pool"),
+            &String::from_str(&env, "Test"),
+            &1_000u128,
+            &100_000u64,
+            &OverfundingPolicy::AcceptAll,
+            &Address::generate(&env),
+            &Some(0u32),
+        ),
+        Err(Ok(ContractError::InvalidMaxRecipients.into()))
+    );
+}
+
+// ============= ATTESTATION TESTS =============
+
+/// Test 1: Pools requiring attestations only accept attested students
+#[test]
+fn test_apply_requires_valid_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let enrolled = Symbol::new(&env, "enrolled");
+    let student = Address::generate(&env);
+    let payload = sample_payload(&env, "Application");
+
+    client.set_required_attestations(&pool_id, &Vec::from_array(&env, [enrolled.clone()]));
+    assert_eq!(
+        client.get_required_attestations(&pool_id),
+        Vec::from_array(&env, [enrolled.clone()])
+    );
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::AttestationMissing.into()))
+    );
+
+    env.ledger().set_timestamp(1_000);
+    client.issue_attestation(&school, &student, &enrolled, &2_000u64);
+    assert_eq!(
+        client.get_attestation(&school, &student, &enrolled),
+        Some(Attestation {
+            issued_at: 1_000,
+            expires_at: 2_000,
+        })
+    );
+
+    // Expired attestations do not count
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::AttestationMissing.into()))
+    );
+
+    client.issue_attestation(&school, &student, &enrolled, &3_000u64);
+    client.apply_to_pool(&pool_id, &student, &payload);
+}
+
+/// Test 2: Attestations from other schools, or revoked ones, are not accepted
+#[test]
+fn test_attestation_must_come_from_linked_school() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let enrolled = Symbol::new(&env, "enrolled");
+    let student = Address::generate(&env);
+    let payload = sample_payload(&env, "Application");
+    client.set_required_attestations(&pool_id, &Vec::from_array(&env, [enrolled.clone()]));
+
+    let other_school = Address::generate(&env);
+    assert_eq!(
+        client.try_issue_attestation(&other_school, &student, &enrolled, &2_000u64),
+        Err(Ok(ContractError::SchoolNotRegistered.into()))
+    );
+    client.register_school(&other_school, &BytesN::from_array(&env, &[2u8; 32]));
+    client.issue_attestation(&other_school, &student, &enrolled, &2_000u64);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::AttestationMissing.into()))
+    );
+
+    client.issue_attestation(&school, &student, &enrolled, &2_000u64);
+    client.revoke_attestation(&school, &student, &enrolled);
+    assert_eq!(client.get_attestation(&school, &student, &enrolled), None);
+    assert_eq!(
+        client.try_apply_to_pool(&pool_id, &student, &payload),
+        Err(Ok(ContractError::AttestationMissing.into()))
+    );
+    assert_eq!(
+        client.try_revoke_attestation(&school, &student, &enrolled),
+        Err(Ok(ContractError::AttestationNotFound.into()))
+    );
+    assert_eq!(
+        client.try_issue_attestation(&school, &student, &enrolled, &0u64),
+        Err(Ok(ContractError::InvalidAttestationExpiry.into()))
+    );
+}
+
+/// Test 3: Only pools linked to a school can require attestations
+#[test]
+fn test_required_attestations_need_linked_school() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let pool_id = client.create_pool(
+        &Address::generate(&env),
+        &String::from_str(&env, "Pool"),
+        &String::from_str(&env, "Test"),
+        &1_000u128,
+        &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
+    );
+
+    assert_eq!(
+        client.try_set_required_attestations(
+            &pool_id,
+            &Vec::from_array(&env, [Symbol::new(&env, "enrolled")])
+        ),
+        Err(Ok(ContractError::PoolSchoolNotSet.into()))
+    );
+}
+
+// ============= MILESTONE LIFECYCLE TESTS =============
+
+fn sample_milestone(env: &Env, amount: u128, due_at: u64) -> Milestone {
+    Milestone {
+        amount,
+        description_hash: BytesN::from_array(env, &[3u8; 32]),
+        due_at,
+        status: MilestoneStatus::Pending,
+    }
+}
+
+/// Test 1: Claims release only whole verified milestones
+#[test]
+fn test_claim_funds_limited_to_verified_milestones() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    client.approve_application(&pool_id, &school, &student, &true, &1_000i128);
+    client.setup_application_milestones(
+        &pool_id,
+        &student,
+        &Vec::from_array(
+            &env,
+            [
+                sample_milestone(&env, 400, 50_000),
+                sample_milestone(&env, 600, 90_000),
+            ],
+        ),
+    );
+    client.approve_milestone_plan(&pool_id, &school, &student);
+    // Nothing vests before the cliff, so a verified milestone waits to be claimed
+    client.set_vesting_schedule(
+        &pool_id,
+        &student,
+        &VestingSchedule {
+            start: 0,
+            cliff: 10_000,
+            end: 20_000,
+        },
+    );
+
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &400i128, &token),
+        Err(Ok(ContractError::ExceedsVerifiedMilestones.into()))
+    );
+
+    client.submit_milestone_evidence(
+        &pool_id,
+        &student,
+        &0,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &300i128, &token),
+        Err(Ok(ContractError::PartialMilestoneClaim.into()))
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &1_000i128, &token),
+        Err(Ok(ContractError::ExceedsVerifiedMilestones.into()))
+    );
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &400i128, &token),
+        Err(Ok(DisbursementError::ExceedsVestedAmount.into()))
+    );
+
+    env.ledger().set_timestamp(20_000);
+    client.claim_funds(&student, &pool_id, &400i128, &token);
+    let milestones = client.get_milestones(&pool_id, &student);
+    assert_eq!(milestones.get(0).unwrap().status, MilestoneStatus::Paid);
+    assert_eq!(milestones.get(1).unwrap().status, MilestoneStatus::Pending);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &400i128, &token),
+        Err(Ok(ContractError::ExceedsVerifiedMilestones.into()))
+    );
+}
+
+/// Test 2: New plans must be pending and not yet due; overdue milestones expire
+#[test]
+fn test_milestone_plan_validation_and_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    env.ledger().set_timestamp(1_000);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    approve_student(&env, &client, pool_id, &student, 1_000);
+
+    let mut verified = sample_milestone(&env, 1_000, 50_000);
+    verified.status = MilestoneStatus::Verified;
+    assert_eq!(
+        client.try_setup_application_milestones(
+            &pool_id,
+            &student,
+            &Vec::from_array(&env, [verified])
+        ),
+        Err(Ok(ContractError::InvalidMilestonePlan.into()))
+    );
+    assert_eq!(
+        client.try_setup_application_milestones(
+            &pool_id,
+            &student,
+            &Vec::from_array(&env, [sample_milestone(&env, 1_000, 1_000)])
+        ),
+        Err(Ok(ContractError::InvalidMilestonePlan.into()))
+    );
+
+    client.setup_application_milestones(
+        &pool_id,
+        &student,
+        &Vec::from_array(
+            &env,
+            [
+                sample_milestone(&env, 400, 5_000),
+                sample_milestone(&env, 600, 9_000),
+            ],
+        ),
+    );
+    client.approve_milestone_plan(&pool_id, &school, &student);
+    env.ledger().set_timestamp(5_001);
+    let milestones = client.get_milestones(&pool_id, &student);
+    assert_eq!(milestones.get(0).unwrap().status, MilestoneStatus::Expired);
+    assert_eq!(milestones.get(1).unwrap().status, MilestoneStatus::Pending);
+}
+
+/// Test 3: Plans are proposed by approved students, sized to the approved amount, and activated by the school
+#[test]
+fn test_milestone_plan_requires_school_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    let plan = Vec::from_array(
+        &env,
+        [
+            sample_milestone(&env, 300, 50_000),
+            sample_milestone(&env, 300, 90_000),
+        ],
+    );
+
+    client.apply_to_pool(&pool_id, &student, &sample_payload(&env, "Application"));
+    assert_eq!(
+        client.try_setup_application_milestones(&pool_id, &student, &plan),
+        Err(Ok(DisbursementError::ApplicationNotApproved.into()))
+    );
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    client.approve_application(&pool_id, &school, &student, &true, &600i128);
+    assert_eq!(
+        client.try_setup_application_milestones(
+            &pool_id,
+            &student,
+            &Vec::from_array(&env, [sample_milestone(&env, 1_000, 50_000)])
+        ),
+        Err(Ok(DisbursementError::MilestoneTotalMismatch.into()))
+    );
+    assert_eq!(
+        client.try_approve_milestone_plan(&pool_id, &school, &student),
+        Err(Ok(DisbursementError::MilestonePlanNotFound.into()))
+    );
+
+    client.setup_application_milestones(&pool_id, &student, &plan);
+    let proposed = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("mile_prop").to_val())
+        })
+        .unwrap();
+    let (proposer, count): (Address, u32) = proposed.2.into_val(&env);
+    assert_eq!(proposer, student);
+    assert_eq!(count, 2);
+
+    assert_eq!(
+        client.get_proposed_milestones(&pool_id, &student),
+        Some(plan.clone())
+    );
+    assert_eq!(client.get_milestones(&pool_id, &student).len(), 0);
+    assert_eq!(
+        client.try_approve_milestone_plan(&pool_id, &Address::generate(&env), &student),
+        Err(Ok(ContractError::OnlyLinkedSchoolCanApprove.into()))
+    );
+
+    // Nothing can be claimed, nor the award resized, while the plan awaits approval
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &600i128, &token),
+        Err(Ok(ContractError::ExceedsVerifiedMilestones.into()))
+    );
+    assert_eq!(
+        client.try_adjust_approved_amount(&pool_id, &school, &student, &300i128),
+        Err(Ok(DisbursementError::MilestoneTotalMismatch.into()))
+    );
+
+    client.approve_milestone_plan(&pool_id, &school, &student);
+    assert_eq!(client.get_milestones(&pool_id, &student), plan);
+    assert_eq!(client.get_proposed_milestones(&pool_id, &student), None);
+    assert_eq!(
+        client.try_adjust_approved_amount(&pool_id, &school, &student, &300i128),
+        Err(Ok(DisbursementError::MilestoneTotalMismatch.into()))
+    );
+
+    // A plan cannot be laid over an award that has already been drawn on
+    let claimant = Address::generate(&env);
+    approve_student(&env, &client, pool_id, &claimant, 400);
+    client.claim_funds(&claimant, &pool_id, &100i128, &token);
+    assert_eq!(
+        client.try_setup_application_milestones(
+            &pool_id,
+            &claimant,
+            &Vec::from_array(&env, [sample_milestone(&env, 400, 50_000)])
+        ),
+        Err(Ok(ContractError::ClaimAlreadyMade.into()))
+    );
+}
+
+/// Test 4: Paying a milestone freezes the plan
+#[test]
+fn test_milestone_plan_frozen_after_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    let plan = Vec::from_array(
+        &env,
+        [
+            sample_milestone(&env, 400, 50_000),
+            sample_milestone(&env, 600, 90_000),
+        ],
+    );
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    approve_student(&env, &client, pool_id, &student, 1_000);
+    client.setup_application_milestones(&pool_id, &student, &plan);
+    client.approve_milestone_plan(&pool_id, &school, &student);
+
+    // Replacing an unpaid plan is still allowed.
+    client.setup_application_milestones(&pool_id, &student, &plan);
+    client.submit_milestone_evidence(
+        &pool_id,
+        &student,
+        &0,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+
+    assert_eq!(
+        client.try_approve_milestone_plan(&pool_id, &school, &student),
+        Err(Ok(DisbursementError::MilestonePlanFrozen.into()))
+    );
+    assert_eq!(
+        client.try_setup_application_milestones(&pool_id, &student, &plan),
+        Err(Ok(DisbursementError::MilestonePlanFrozen.into()))
+    );
+}
+
+/// Approve `student` for 1_000 with an active two-milestone plan of 400 and 600.
+fn setup_milestone_plan(
+    env: &Env,
+    client: &ContractClient,
+    school: &Address,
+    pool_id: u32,
+    student: &Address,
+) {
+    fund_and_donate(env, client, pool_id, &Address::generate(env), 1_000u128);
+    approve_student(env, client, pool_id, student, 1_000);
+    client.setup_application_milestones(
+        &pool_id,
+        student,
+        &Vec::from_array(
+            env,
+            [
+                sample_milestone(env, 400, 50_000),
+                sample_milestone(env, 600, 90_000),
+            ],
+        ),
+    );
+    client.approve_milestone_plan(&pool_id, school, student);
+}
+
+/// Test 5: Verifying submitted evidence pays the milestone automatically
+#[test]
+fn test_verified_milestone_pays_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+
+    client.submit_milestone_evidence(&pool_id, &student, &0, &evidence);
+    let submitted = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("mile_sub").to_val())
+        })
+        .unwrap();
+    let (submitter, index, hash): (Address, u32, BytesN<32>) = submitted.2.into_val(&env);
+    assert_eq!(
+        (submitter, index, hash),
+        (student.clone(), 0, evidence.clone())
+    );
+
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+    let events = env.events().all();
+    let verified = events
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("mile_vrfy").to_val())
+        })
+        .unwrap();
+    let (_, _, accepted): (Address, u32, bool) = verified.2.into_val(&env);
+    assert!(accepted);
+    let paid = events
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("mile_paid").to_val())
+        })
+        .unwrap();
+    let (_, index, amount): (Address, u32, i128) = paid.2.into_val(&env);
+    assert_eq!((index, amount), (0, 400));
+
+    assert_eq!(token::Client::new(&env, &token).balance(&student), 396);
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 400);
+    assert_eq!(
+        client
+            .get_milestones(&pool_id, &student)
+            .get(0)
+            .unwrap()
+            .status,
+        MilestoneStatus::Paid
+    );
+    assert_eq!(
+        client.get_milestone_evidence(&pool_id, &student, &0),
+        Some(evidence)
+    );
+    assert_eq!(
+        client.try_verify_milestone(&pool_id, &school, &student, &0, &true),
+        Err(Ok(DisbursementError::InvalidMilestoneStatus.into()))
+    );
+}
+
+/// Test 6: Turned-down evidence can be resubmitted; only the school verifies
+#[test]
+fn test_rejected_milestone_evidence_can_be_resubmitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+
+    assert_eq!(
+        client.try_submit_milestone_evidence(&pool_id, &student, &2, &evidence),
+        Err(Ok(DisbursementError::MilestoneNotFound.into()))
+    );
+    assert_eq!(
+        client.try_verify_milestone(&pool_id, &school, &student, &1, &true),
+        Err(Ok(DisbursementError::InvalidMilestoneStatus.into()))
+    );
+
+    client.submit_milestone_evidence(&pool_id, &student, &1, &evidence);
+    assert_eq!(
+        client.try_submit_milestone_evidence(&pool_id, &student, &1, &evidence),
+        Err(Ok(DisbursementError::InvalidMilestoneStatus.into()))
+    );
+    assert_eq!(
+        client.try_verify_milestone(&pool_id, &Address::generate(&env), &student, &1, &true),
+        Err(Ok(ContractError::OnlyLinkedSchoolCanApprove.into()))
+    );
+
+    client.verify_milestone(&pool_id, &school, &student, &1, &false);
+    assert_eq!(
+        client
+            .get_milestones(&pool_id, &student)
+            .get(1)
+            .unwrap()
+            .status,
+        MilestoneStatus::Pending
+    );
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 0);
+
+    client.submit_milestone_evidence(&pool_id, &student, &1, &evidence);
+    env.ledger().set_timestamp(90_001);
+    assert_eq!(
+        client.try_verify_milestone(&pool_id, &school, &student, &1, &true),
+        Err(Ok(DisbursementError::InvalidMilestoneStatus.into()))
+    );
+}
+
+// ============= VESTING TESTS =============
+
+/// Test 1: Claims are limited to the linearly vested amount
+#[test]
+fn test_claim_funds_limited_to_vested_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    approve_student(&env, &client, pool_id, &student, 1_000);
+
+    let schedule = VestingSchedule {
+        start: 1_000,
+        cliff: 2_000,
+        end: 5_000,
+    };
+    client.set_vesting_schedule(&pool_id, &student, &schedule);
+    assert_eq!(
+        client.get_vesting_schedule(&pool_id, &student),
+        Some(schedule)
+    );
+
+    env.ledger().set_timestamp(1_500);
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &1i128, &token),
+        Err(Ok(DisbursementError::ExceedsVestedAmount.into()))
+    );
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 250);
+    client.claim_funds(&student, &pool_id, &200i128, &token);
+    assert_eq!(client.get_claimable(&pool_id, &student), 50);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        Err(Ok(DisbursementError::ExceedsVestedAmount.into()))
+    );
+
+    env.ledger().set_timestamp(4_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 550);
+
+    env.ledger().set_timestamp(6_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 800);
+    client.claim_funds(&student, &pool_id, &800i128, &token);
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+}
+
+/// Test 2: Only approved, unclaimed awards accept a well-ordered schedule
+#[test]
+fn test_set_vesting_schedule_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    let schedule = VestingSchedule {
+        start: 1_000,
+        cliff: 1_000,
+        end: 5_000,
+    };
+
+    assert_eq!(
+        client.try_set_vesting_schedule(&pool_id, &student, &schedule),
+        Err(Ok(DisbursementError::ApplicationNotApproved.into()))
+    );
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    approve_student(&env, &client, pool_id, &student, 1_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 1_000);
+    for (start, cliff, end) in [
+        (5_000, 5_000, 5_000),
+        (2_000, 1_000, 5_000),
+        (1_000, 6_000, 5_000),
+    ] {
+        assert_eq!(
+            client.try_set_vesting_schedule(
+                &pool_id,
+                &student,
+                &VestingSchedule { start, cliff, end }
+            ),
+            Err(Ok(DisbursementError::InvalidVestingSchedule.into()))
+        );
+    }
+
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+    assert_eq!(
+        client.try_set_vesting_schedule(&pool_id, &student, &schedule),
+        Err(Ok(ContractError::ClaimAlreadyMade.into()))
+    );
+}
+
+/// Test 3: Verified milestones are only paid once vested
+#[test]
+fn test_vesting_limits_milestone_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    client.set_vesting_schedule(
+        &pool_id,
+        &student,
+        &VestingSchedule {
+            start: 1_000,
+            cliff: 10_000,
+            end: 20_000,
+        },
+    );
+    let evidence = BytesN::from_array(&env, &[4u8; 32]);
+    let status = |index: u32| {
+        client
+            .get_milestones(&pool_id, &student)
+            .get(index)
+            .unwrap()
+            .status
+    };
+
+    env.ledger().set_timestamp(5_000);
+    client.submit_milestone_evidence(&pool_id, &student, &0, &evidence);
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+    assert_eq!(status(0), MilestoneStatus::Verified);
+    assert_eq!(client.get_claimed_amount(&pool_id, &student), 0);
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &400i128, &token),
+        Err(Ok(DisbursementError::ExceedsVestedAmount.into()))
+    );
+
+    // 473 of the 1_000 has vested: enough for the first milestone only
+    env.ledger().set_timestamp(10_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 400);
+    client.claim_funds(&student, &pool_id, &400i128, &token);
+    assert_eq!(status(0), MilestoneStatus::Paid);
+
+    client.submit_milestone_evidence(&pool_id, &student, &1, &evidence);
+    client.verify_milestone(&pool_id, &school, &student, &1, &true);
+    assert_eq!(status(1), MilestoneStatus::Verified);
+    assert_eq!(client.get_claimable(&pool_id, &student), 0);
+
+    env.ledger().set_timestamp(20_000);
+    assert_eq!(client.get_claimable(&pool_id, &student), 600);
+    client.claim_funds(&student, &pool_id, &600i128, &token);
+    assert_eq!(status(1), MilestoneStatus::Paid);
+    assert_eq!(token::Client::new(&env, &token).balance(&student), 990);
+}
+
+// ============= MILESTONE AMENDMENT TESTS =============
+
+/// Test 1: An amendment replaces unpaid milestones once student and school agree
+#[test]
+fn test_milestone_amendment_keeps_paid_milestones_and_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    let original = client.get_milestones(&pool_id, &student);
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 1);
+
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+    client.submit_milestone_evidence(&pool_id, &student, &0, &evidence);
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+
+    let replacement = Vec::from_array(
+        &env,
+        [
+            sample_milestone(&env, 300, 70_000),
+            sample_milestone(&env, 300, 95_000),
+        ],
+    );
+    client.propose_milestone_amendment(&pool_id, &school, &student, &replacement);
+    let amendment = client.get_milestone_amendment(&pool_id, &student).unwrap();
+    assert!(amendment.school_consent);
+    assert!(!amendment.student_consent);
+    assert_eq!(client.get_milestones(&pool_id, &student).len(), 2);
+
+    assert_eq!(
+        client.try_consent_milestone_amendment(&pool_id, &Address::generate(&env), &student),
+        Err(Ok(DisbursementError::NotAmendmentParty.into()))
+    );
+    assert!(client.consent_milestone_amendment(&pool_id, &student, &student));
+    let applied = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("amd_apld").to_val())
+        })
+        .unwrap();
+    let (_, version): (Address, u32) = applied.2.into_val(&env);
+    assert_eq!(version, 2);
+
+    let milestones = client.get_milestones(&pool_id, &student);
+    assert_eq!(milestones.len(), 3);
+    assert_eq!(milestones.get(0).unwrap().status, MilestoneStatus::Paid);
+    assert_eq!(milestones.get(0).unwrap().amount, 400);
+    assert_eq!(milestones.get(2).unwrap().due_at, 95_000);
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 2);
+    assert_eq!(
+        client.get_milestone_plan_at(&pool_id, &student, &1),
+        Some(original)
+    );
+    assert_eq!(
+        client.get_milestone_plan_at(&pool_id, &student, &2),
+        Some(milestones)
+    );
+    assert_eq!(client.get_milestone_amendment(&pool_id, &student), None);
+}
+
+/// Test 2: Pools can require the sponsor's consent as well
+#[test]
+fn test_milestone_amendment_requires_sponsor_when_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let sponsor = client.get_pool(&pool_id).1;
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    client.set_amendment_sponsor_consent(&pool_id, &true);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert!(topics
+        .get(0)
+        .unwrap()
+        .shallow_eq(&symbol_short!("amd_spon").to_val()));
+    let required: bool = data.into_val(&env);
+    assert!(required);
+    assert!(client.get_amendment_sponsor_consent(&pool_id));
+
+    assert_eq!(
+        client.try_propose_milestone_amendment(
+            &pool_id,
+            &sponsor,
+            &student,
+            &Vec::from_array(&env, [sample_milestone(&env, 1_000, 80_000)])
+        ),
+        Err(Ok(DisbursementError::NotAmendmentParty.into()))
+    );
+    client.propose_milestone_amendment(
+        &pool_id,
+        &student,
+        &student,
+        &Vec::from_array(&env, [sample_milestone(&env, 1_000, 80_000)]),
+    );
+    assert!(!client.consent_milestone_amendment(&pool_id, &school, &student));
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 1);
+
+    assert!(client.consent_milestone_amendment(&pool_id, &sponsor, &student));
+    assert_eq!(client.get_milestone_plan_version(&pool_id, &student), 2);
+    assert_eq!(client.get_milestones(&pool_id, &student).len(), 1);
+    assert_eq!(
+        client.try_consent_milestone_amendment(&pool_id, &school, &student),
+        Err(Ok(DisbursementError::AmendmentNotFound.into()))
+    );
+
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    client.cancel_pool(&pool_id, &client.get_pool(&pool_id).1);
+    client.close_pool(&pool_id);
+    assert_eq!(
+        client.try_set_amendment_sponsor_consent(&pool_id, &true),
+        Err(Ok(ContractError::PoolIsClosed.into()))
+    );
+}
+
+/// Test 3: With paid milestones kept, the amended plan still totals the approved amount
+#[test]
+fn test_milestone_amendment_total_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+    setup_milestone_plan(&env, &client, &school, pool_id, &student);
+    client.submit_milestone_evidence(
+        &pool_id,
+        &student,
+        &0,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.verify_milestone(&pool_id, &school, &student, &0, &true);
+
+    for amount in [500, 700] {
+        assert_eq!(
+            client.try_propose_milestone_amendment(
+                &pool_id,
+                &student,
+                &student,
+                &Vec::from_array(&env, [sample_milestone(&env, amount, 80_000)])
+            ),
+            Err(Ok(DisbursementError::MilestoneTotalMismatch.into()))
+        );
+    }
+    client.propose_milestone_amendment(
+        &pool_id,
+        &student,
+        &student,
+        &Vec::from_array(&env, [sample_milestone(&env, 600, 80_000)]),
+    );
+}
+
+// ============= PAYEE TESTS =============
+
+/// Test 1: School-payee claims go to the school's payout address and name it in the event
+#[test]
+fn test_school_payee_routes_claims_to_payout_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, school, pool_id) = setup_school_pool(&env);
+    let token = client.get_pool_token(&pool_id);
+    let token_client = token::Client::new(&env, &token);
+    let student = Address::generate(&env);
+    let payout = Address::generate(&env);
+    fund_and_donate(&env, &client, pool_id, &Address::generate(&env), 1_000u128);
+    approve_student(&env, &client, pool_id, &student, 1_000);
+
+    assert_eq!(
+        client.get_application_payee(&pool_id, &student),
+        Payee::Student
+    );
+    client.set_application_payee(&pool_id, &student, &Payee::School);
+    assert_eq!(
+        client.try_claim_funds(&student, &pool_id, &100i128, &token),
+        Err(Ok(DisbursementError::PayoutAddressNotSet.into()))
+    );
+
+    client.set_school_payout_address(&school, &payout);
+    assert_eq!(
+        client.get_school_payout_address(&school),
+        Some(payout.clone())
+    );
+    client.claim_funds(&student, &pool_id, &100i128, &token);
+    let claimed = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("fund_clmd").to_val())
+        })
+        .unwrap();
+    let (claimant, amount, total, payee): (Address, i128, i128, Address) = claimed.2.into_val(&env);
+    assert_eq!((claimant, amount, total), (student.clone(), 100, 100));
+    assert_eq!(payee, payout);
+
+    assert_eq!(token_client.balance(&payout), 99);
+    assert_eq!(token_client.balance(&student), 0);
+    assert_eq!(
+        client.try_set_application_payee(&pool_id, &student, &Payee::Student),
+        Err(Ok(ContractError::ClaimAlreadyMade.into()))
+    );
+}
+
+/// Test 2: Only registered schools set payout addresses; only approved awards get a payee
+#[test]
+fn test_payee_settings_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _school, pool_id) = setup_school_pool(&env);
+    let student = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_school_payout_address(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(ContractError::SchoolNotRegistered.into()))
+    );
+    assert_eq!(
+        client.try_set_application_payee(&pool_id, &student, &Payee::School),
+        Err(Ok(DisbursementError::ApplicationNotApproved.into()))
+    );
 }
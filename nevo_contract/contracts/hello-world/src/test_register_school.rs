@@ -157,6 +157,9 @@ fn test_registered_school_enables_pool_creation() {
         &1_000_000_000u128,
         &school,
         &100_000u64,
+        &OverfundingPolicy::AcceptAll,
+        &Address::generate(&env),
+        &None,
     );
 
     assert_eq!(client.get_pool_school(&pool_id), school);